@@ -3,10 +3,39 @@
 #[ink::contract]
 mod ronin_mission5_user {
 
+    use ink::storage::Mapping;
     use ink_prelude::string::String;
     use ink_prelude::vec::Vec;
     use scale::{Decode, Encode};
 
+    /* Contract version, bumped on every deployed change */
+    const CONTRACT_VERSION: u32 = 1;
+
+    /* Maximum number of messages returned by a single paged read or search */
+    const MAX_PAGE_SIZE: u32 = 100;
+
+    /* Default minimum message length in characters */
+    const DEFAULT_MIN_LENGTH: u32 = 10;
+
+    /* Default maximum message length in bytes */
+    const DEFAULT_MAX_LENGTH: u32 = 280;
+
+    /* Maximum number of tags per message and maximum tag length in bytes */
+    const MAX_TAGS: usize = 5;
+    const MAX_TAG_LENGTH: usize = 20;
+
+    /* Maximum reaction length in bytes and maximum distinct reactions per message */
+    const MAX_REACTION_LENGTH: usize = 16;
+    const MAX_REACTIONS: usize = 20;
+
+    /* Maximum number of banned words */
+    const MAX_BANNED_WORDS: usize = 50;
+
+    /* Maximum number of previous versions kept per message, the oldest is dropped first
+    *  Keeps the encoded Message within the static storage buffer however many times it is edited
+    */
+    const MAX_HISTORY: usize = 10;
+
     #[derive(Debug, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum CrudError {
@@ -15,17 +44,58 @@ mod ronin_mission5_user {
         MessageIsIdentical,
         AnyMessageFound,
         Unauthorized,
+        MessageNotDeleted,
+        MessageTooLong,
+        ContractPaused,
+        AlreadyLiked,
+        SenderBlocked,
+        SenderQuotaExceeded,
+        InsufficientFee,
+        TransferFailed,
+        TooManyTags,
+        EditWindowExpired,
+        AlreadyVoted,
+        RateLimited,
+        UpgradeFailed,
+        MessageBlank,
+        ContainsBannedWord,
+        TooManyBannedWords,
+        ZeroAddress,
+        MessageLocked,
+        MessageWasDeleted,
+        IndexOutOfBounds,
+        InvalidReaction,
+        Overflow,
+        StorageFull,
+        BatchTooLarge,
+        UnexpectedPayment,
     }
 
     /* Use a custom struct Message instead as (AccountId, String) */
     #[derive(Debug, PartialEq, Eq, Encode, Decode, Clone)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct Message {
         sender: AccountId,
         message: String,
         created_at: Timestamp,
         updated_at: Timestamp,
         deleted_at: Option<Timestamp>,
+        deleted_by: Option<AccountId>, // sender for self-deletes, creator or moderator for admin deletes
+        edit_count: u32,
+        likes: u32,
+        likers: Vec<AccountId>,
+        reply_to: Option<AccountId>,
+        history: Vec<(String, Timestamp)>, // last MAX_HISTORY previous versions with their updated_at
+        tags: Vec<String>,
+        private: bool, // hidden from public feeds, still visible to the creator
+        upvotes: u32,
+        downvotes: u32,
+        voters: Vec<(AccountId, bool)>, // voters with their vote, true for an upvote
+        id: u64, // unique, incrementing identifier assigned on insertion
+        ttl: Option<Timestamp>, // milliseconds after creation before the message is hidden from reads
+        comment_count: u32,
+        locked: bool, // frozen by its sender against updates
+        content_hash: Option<[u8; 32]>, // client provided hash of the message, cleared on update
     }
 
     impl Message {
@@ -36,24 +106,185 @@ mod ronin_mission5_user {
                 created_at,
                 updated_at: created_at, // Set updated_at to created_at (first message is created at the same time as updated_at)
                 deleted_at: None,
+                deleted_by: None,
+                edit_count: 0,
+                likes: 0,
+                likers: Vec::<AccountId>::new(),
+                reply_to: None,
+                history: Vec::<(String, Timestamp)>::new(),
+                tags: Vec::<String>::new(),
+                private: false,
+                upvotes: 0,
+                downvotes: 0,
+                voters: Vec::<(AccountId, bool)>::new(),
+                id: 0,
+                ttl: None,
+                comment_count: 0,
+                locked: false,
+                content_hash: None,
             }
         }
 
-        pub fn delete(&mut self, deleted_at: Timestamp) {
+        pub fn is_active(&self) -> bool {
+            self.deleted_at.is_none()
+        }
+
+        pub fn is_expired(&self, now: Timestamp) -> bool {
+            self.ttl.is_some_and(|ttl: Timestamp| now > self.created_at.saturating_add(ttl))
+        }
+
+        pub fn is_readable(&self, now: Timestamp) -> bool {
+            self.is_active() && !self.is_expired(now)
+        }
+
+        pub fn delete(&mut self, deleted_at: Timestamp, deleted_by: AccountId) {
             self.deleted_at = Some(deleted_at);
+            self.deleted_by = Some(deleted_by);
+        }
+
+        pub fn like(&mut self, liker: AccountId) {
+            self.likers.push(liker);
+            self.likes = self.likes.saturating_add(1);
+        }
+
+        pub fn unlike(&mut self, liker: AccountId) {
+            let before: usize = self.likers.len();
+            self.likers.retain(|l: &AccountId| *l != liker);
+            self.likes = self.likes.saturating_sub((before - self.likers.len()) as u32);
+        }
+
+        pub fn has_voted(&self, voter: AccountId) -> bool {
+            self.voters.iter().any(|(v, _): &(AccountId, bool)| *v == voter)
+        }
+
+        pub fn vote(&mut self, voter: AccountId, upvote: bool) {
+            self.voters.push((voter, upvote));
+            if upvote {
+                self.upvotes = self.upvotes.saturating_add(1);
+            } else {
+                self.downvotes = self.downvotes.saturating_add(1);
+            }
+        }
+
+        pub fn unvote(&mut self, voter: AccountId) {
+            for (_, upvote) in self.voters.iter().filter(|(v, _): &&(AccountId, bool)| *v == voter) {
+                if *upvote {
+                    self.upvotes = self.upvotes.saturating_sub(1);
+                } else {
+                    self.downvotes = self.downvotes.saturating_sub(1);
+                }
+            }
+            self.voters.retain(|(v, _): &(AccountId, bool)| *v != voter);
+        }
+
+        pub fn transfer(&mut self, new_owner: AccountId) {
+            self.sender = new_owner;
+        }
+
+        pub fn restore(&mut self) {
+            self.deleted_at = None;
+            self.deleted_by = None;
         }
 
         pub fn update(&mut self, message: String, updated_at: Timestamp) {
-            self.message = message;
+            let previous: String = core::mem::replace(&mut self.message, message);
+            if self.history.len() >= MAX_HISTORY {
+                self.history.remove(0);
+            }
+            self.history.push((previous, self.updated_at));
             self.updated_at = updated_at;
+            self.edit_count = self.edit_count.saturating_add(1);
+            self.content_hash = None;
         }
     }
 
+    /* Compact message export: (sender, message, created_at, updated_at, deleted_at) */
+    pub type ExportedMessage = (AccountId, String, Timestamp, Timestamp, Option<Timestamp>);
+
+    /* Comment on a message: (commenter, comment, created_at) */
+    pub type Comment = (AccountId, String, Timestamp);
+
+    /* Contract-wide message statistics */
+    #[derive(Debug, PartialEq, Eq, Encode, Decode, Clone)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Stats {
+        total: u32,
+        active: u32,
+        deleted: u32,
+        unique_senders: u32, // senders with at least one active message
+    }
+
+    /* Event emitted when a message is created */
+    #[ink(event)]
+    pub struct MessageCreated {
+        #[ink(topic)]
+        sender: AccountId,
+        message: String,
+        created_at: Timestamp,
+    }
+
+    /* Event emitted when a message is updated, with previous and new content */
+    #[ink(event)]
+    pub struct MessageUpdated {
+        #[ink(topic)]
+        sender: AccountId,
+        old_message: String,
+        new_message: String,
+        updated_at: Timestamp,
+    }
+
+    /* Event emitted when a message is soft-deleted */
+    #[ink(event)]
+    pub struct MessageDeleted {
+        #[ink(topic)]
+        sender: AccountId,
+        deleted_at: Timestamp,
+    }
+
+    /* Event emitted when a message is liked, filterable by liker and message owner */
+    #[ink(event)]
+    pub struct MessageLiked {
+        #[ink(topic)]
+        liker: AccountId,
+        #[ink(topic)]
+        sender: AccountId,
+        likes: u32,
+    }
+
     #[ink(storage)]
     pub struct CrudContract {
-        messages: Vec<Message>,
+        messages: Mapping<u32, Message>, // message log by index, each entry is loaded on its own
+        messages_len: u32, // number of entries in messages, deleted included
+        active_messages: Mapping<AccountId, u32>, // index in messages of each sender's latest active message
+        senders: Vec<AccountId>, // accounts with at least one message in storage, deleted included
+        sender_counts: Mapping<AccountId, u32>, // messages in storage per sender, deleted included
         creator: AccountId, // creator added: to check if caller is contract owner
-        // senders removed: replace by a public get_senders function
+        allow_multiple: bool, // allow senders to have several active messages
+        min_length: u32, // minimum message length in characters
+        max_length: u32, // maximum message length in bytes
+        paused: bool, // freeze create, update and delete
+        blocked: Vec<AccountId>, // senders not allowed to create or update messages
+        public_senders: Mapping<AccountId, bool>, // senders who opted into public readability
+        moderators: Vec<AccountId>, // accounts allowed to moderate besides the creator
+        max_per_sender: u32, // maximum messages ever created per sender, deleted and purged included (0 = unlimited)
+        create_fee: Balance, // value to transfer with create_message
+        pinned: Option<u32>, // index in messages of the message pinned by the creator
+        last_activity: Timestamp, // last successful create, update or delete
+        pending_owner: Option<AccountId>, // proposed owner waiting to accept ownership
+        edit_window: Timestamp, // milliseconds after creation during which a message can be updated (0 = always)
+        trim_on_compare: bool, // trim surrounding whitespace on update before the identical check
+        next_id: u64, // id of the next inserted message
+        create_counts: Mapping<AccountId, u32>, // messages ever created per sender
+        last_creates: Mapping<AccountId, Timestamp>, // last create timestamp per sender
+        cooldown: Timestamp, // minimal milliseconds between two creates of a sender (0 = no limit)
+        comments: Mapping<AccountId, Vec<Comment>>, // comments on the active message of each sender
+        banned_words: Vec<String>, // lowercase words rejected in created and updated messages
+        welcome_text: Option<String>, // onboarding text set by the creator
+        accrued_fees: Balance, // fees collected by create_message and not withdrawn yet
+        reactions: Mapping<(u32, String), u32>, // (message index, reaction) to reaction count
+        reaction_keys: Mapping<u32, Vec<String>>, // message index to its distinct reactions
+        public_read_all: bool, // true if any caller can read all messages
+        max_messages: u32, // maximum messages stored, deleted included (0 = unlimited)
     }
 
     impl CrudContract {
@@ -63,193 +294,5140 @@ mod ronin_mission5_user {
         pub fn new() -> Self {
             let creator: AccountId = Self::env().caller();
 
-            let mut messages: Vec<Message> = Vec::<Message>::new();
-
             let init_message: String = String::from("I created my ULTIMATE CRUD contract for Ronin Club");
 
-            messages.push(Message::new(creator, init_message, Self::env().block_timestamp()));
+            let created_at: Timestamp = Self::env().block_timestamp();
+
+            let mut messages = Mapping::default();
+            messages.insert(0, &Message::new(creator, init_message, created_at));
+
+            let mut active_messages = Mapping::default();
+            active_messages.insert(creator, &0);
+
+            let mut sender_counts = Mapping::default();
+            sender_counts.insert(creator, &1);
+
+            Self {
+                messages,
+                messages_len: 1,
+                active_messages,
+                senders: ink_prelude::vec![creator],
+                sender_counts,
+                creator,
+                allow_multiple: false,
+                min_length: DEFAULT_MIN_LENGTH,
+                max_length: DEFAULT_MAX_LENGTH,
+                paused: false,
+                blocked: Vec::<AccountId>::new(),
+                public_senders: Mapping::default(),
+                moderators: Vec::<AccountId>::new(),
+                max_per_sender: 0,
+                create_fee: 0,
+                pinned: None,
+                last_activity: created_at,
+                pending_owner: None,
+                edit_window: 0,
+                trim_on_compare: false,
+                next_id: 1, // genesis message has id 0
+                create_counts: Mapping::default(),
+                last_creates: Mapping::default(),
+                cooldown: 0,
+                comments: Mapping::default(),
+                banned_words: Vec::<String>::new(),
+                welcome_text: None,
+                accrued_fees: 0,
+                reactions: Mapping::default(),
+                reaction_keys: Mapping::default(),
+                public_read_all: false,
+                max_messages: 0,
+            }
+        }
+
+        /* Constructor - Seed a custom genesis message
+        *  Panics if the genesis message is shorter than the minimal length, reverting instantiation
+        */
+        #[ink(constructor)]
+        pub fn new_with_genesis(genesis: String) -> Self {
+            let mut contract: Self = Self::new();
+
+            /* Verify if genesis message has the minimal length */
+            assert!(contract.is_message_too_short(&genesis).is_ok(), "Genesis message is too short");
+
+            let mut genesis_message: Message = contract.messages.get(0).unwrap();
+            genesis_message.message = genesis;
+            contract.messages.insert(0, &genesis_message);
+
+            contract
+        }
+
+        /* Constructor - Allow several active messages per sender */
+        #[ink(constructor)]
+        pub fn new_multi(allow_multiple: bool) -> Self {
+            let mut contract: Self = Self::new();
+            contract.allow_multiple = allow_multiple;
+            contract
+        }
+
+        /* Constructor - Set a custom minimum message length */
+        #[ink(constructor)]
+        pub fn new_with_min(min_length: u32) -> Self {
+            let mut contract: Self = Self::new();
+            contract.min_length = min_length;
+            contract
+        }
+
+        /* Constructor - Set a custom maximum message length */
+        #[ink(constructor)]
+        pub fn new_with_max_length(max_length: u32) -> Self {
+            let mut contract: Self = Self::new();
+            contract.max_length = max_length;
+            contract
+        }
+
+        /* Constructor - Cap the number of messages a sender can ever create (0 = unlimited) */
+        #[ink(constructor)]
+        pub fn new_with_max_per_sender(max_per_sender: u32) -> Self {
+            let mut contract: Self = Self::new();
+            contract.max_per_sender = max_per_sender;
+            contract
+        }
+
+        /* Constructor - Cap the number of messages stored, deleted included (0 = unlimited)
+        *  Purging messages frees slots
+        */
+        #[ink(constructor)]
+        pub fn new_with_max_messages(max_messages: u32) -> Self {
+            let mut contract: Self = Self::new();
+            contract.max_messages = max_messages;
+            contract
+        }
+
+        /* Constructor - Only allow updates within edit_window milliseconds after creation (0 = always) */
+        #[ink(constructor)]
+        pub fn new_with_edit_window(edit_window: Timestamp) -> Self {
+            let mut contract: Self = Self::new();
+            contract.edit_window = edit_window;
+            contract
+        }
+
+        /* Constructor - Trim surrounding whitespace of updates before the identical check */
+        #[ink(constructor)]
+        pub fn new_with_trim(trim_on_compare: bool) -> Self {
+            let mut contract: Self = Self::new();
+            contract.trim_on_compare = trim_on_compare;
+            contract
+        }
 
-            Self { messages, creator }
+        /* Constructor - Require cooldown milliseconds between two creates of a sender (0 = no limit) */
+        #[ink(constructor)]
+        pub fn new_with_cooldown(cooldown: Timestamp) -> Self {
+            let mut contract: Self = Self::new();
+            contract.cooldown = cooldown;
+            contract
+        }
+
+        /* Constructor - Require a fee to create a message */
+        #[ink(constructor)]
+        pub fn new_with_fee(create_fee: Balance) -> Self {
+            let mut contract: Self = Self::new();
+            contract.create_fee = create_fee;
+            contract
         }
 
 
         /* Public function - Create a message
-        *  Check if message has already been created by sender
-        *  Check if message has a minimal length of 10
+        *  Check if transferred value covers the create fee
+        *  Check if message has already been created by sender (skipped in multiple mode)
+        *  Check if message has the minimal length
+        *  Check if message does not exceed the maximal length
         */
-        #[ink(message)]
+        #[ink(message, payable)]
         pub fn create_message(&mut self, message: String) -> Result<(), CrudError> {
+            self.create_message_indexed(message).map(|_| ())
+        }
+
+        /* Public function - Create a message and return its index in messages
+        *  Same checks as create_message
+        */
+        #[ink(message, payable)]
+        pub fn create_message_indexed(&mut self, message: String) -> Result<u32, CrudError> {
             let caller: AccountId = self.env().caller();
 
-            /* Verify if message has already been created by sender */
-            self.can_create_message(caller)?;
+            self.insert_message(Message::new(caller, message, Self::env().block_timestamp()))
+        }
+
+        /* Public function - Validate a message without creating it
+        *  Check if message is not blank
+        *  Check if message has the minimal length
+        *  Check if message does not exceed the maximal length
+        *  Check if message does not contain a banned word
+        */
+        #[ink(message)]
+        pub fn validate_message(&self, message: String) -> Result<(), CrudError> {
 
-            /* Verify if message has a minimal length of 10 */
-            self.is_message_too_short(&message)?;
+            /* Verify if message is not blank */
+            self.is_message_blank(&message)?;
 
-            // insert message
-            self.messages.push(Message::new(caller, message, Self::env().block_timestamp()));
+            /* Verify if message length is between minimal and maximal length */
+            self.is_message_length_valid(&message)?;
 
-            Ok(())
+            /* Verify if message does not contain a banned word */
+            self.contains_banned_word(&message)
         }
 
-        /* Public function - Return a message from sender
-        *  Check sender has not deleted message in storage
+        /* Public function - Reply to the message of another sender
+        *  Check if replied sender has a message not deleted
+        *  Same checks as create_message
         */
-        #[ink(message)]
-        pub fn read_message_from(&mut self, caller: AccountId) -> Result<String, CrudError> {
-
-            let caller_mesage: Option<Message> = self.get_caller_message(caller);
+        #[ink(message, payable)]
+        pub fn create_reply(&mut self, message: String, reply_to: AccountId) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
 
-            /* Verify if sender has not deleted message in storage */
-            if caller_mesage.is_some() {
-                return Ok(caller_mesage.unwrap().message);
-            } else {
+            /* Verify if replied sender has a message */
+            if self.get_caller_message(reply_to).is_none() {
                 return Err(CrudError::AnyMessageFound);
             }
+
+            let mut reply: Message = Message::new(caller, message, Self::env().block_timestamp());
+            reply.reply_to = Some(reply_to);
+
+            self.insert_message(reply).map(|_| ())
         }
 
-        /* Public function - Read all messages
-        *  Check if caller is contract creator
+        /* Public function - Create a message with tags
+        *  Check if there are at most MAX_TAGS tags of at most MAX_TAG_LENGTH bytes
+        *  Same checks as create_message
         */
-        #[ink(message)]
-        pub fn read_all_messages(&mut self) -> Result<Vec<Message>, CrudError> {
+        #[ink(message, payable)]
+        pub fn create_tagged_message(&mut self, message: String, tags: Vec<String>) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
 
-            /* Verify if caller is contract owner */
-            self.is_authorized(self.env().caller())?;
+            /* Verify tags count and length */
+            if tags.len() > MAX_TAGS || tags.iter().any(|t: &String| t.len() > MAX_TAG_LENGTH) {
+                return Err(CrudError::TooManyTags);
+            }
 
-            let all_messages: Vec<Message> = self.get_all_messages_from_storage();
+            let mut tagged: Message = Message::new(caller, message, Self::env().block_timestamp());
+            tagged.tags = tags;
 
-            /* Verify if messages is empty */
-            if all_messages.is_empty() {
-                return Err(CrudError::AnyMessageFound);
-            }
+            self.insert_message(tagged).map(|_| ())
+        }
+
+        /* Public function - Create a message hidden from reads ttl milliseconds after creation
+        *  Same checks as create_message
+        */
+        #[ink(message, payable)]
+        pub fn create_message_with_ttl(&mut self, message: String, ttl: Timestamp) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
 
-            return Ok(all_messages);
+            let mut expiring: Message = Message::new(caller, message, Self::env().block_timestamp());
+            expiring.ttl = Some(ttl);
+
+            self.insert_message(expiring).map(|_| ())
         }
 
-        /* Public function - Update caller message
-        *  Check if message has already been created by sender and not deleted
-        *  Check if message has a minimal length of 10
-        *  Check if last message is identical
+        /* Public function - Create a message with a client provided content hash
+        *  The hash is not checked against the message, it is cleared when the message is updated
+        *  Same checks as create_message
         */
-        #[ink(message)]
-        pub fn update_message(&mut self, message: String) -> Result<(), CrudError> {
+        #[ink(message, payable)]
+        pub fn create_message_with_hash(&mut self, message: String, hash: [u8; 32]) -> Result<(), CrudError> {
             let caller: AccountId = self.env().caller();
 
-            /* Verify if message has already been created by sender */
-            self.can_edit_message(caller)?;
+            let mut hashed: Message = Message::new(caller, message, Self::env().block_timestamp());
+            hashed.content_hash = Some(hash);
 
-            /* Verify if message has a minimal length of 10 */
-            self.is_message_too_short(&message)?;
+            self.insert_message(hashed).map(|_| ())
+        }
 
-            /* Verify if last message is identical */
-            if self.get_caller_message(caller).unwrap().message == message {
-                return Err(CrudError::MessageIsIdentical);
+        /* Public function - Check if sender message content hash matches expected
+        *  Check sender has not deleted message in storage
+        *  Return false if the message has no content hash
+        */
+        #[ink(message)]
+        pub fn verify_hash(&self, sender: AccountId, expected: [u8; 32]) -> Result<bool, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.content_hash == Some(expected)),
+                None => Err(CrudError::AnyMessageFound),
             }
+        }
 
-            // Update message using struct method
-            self.get_caller_mut_message(caller).unwrap().update(message, Self::env().block_timestamp());
+        /* Public function - Create a private message
+        *  Private messages are hidden from public feeds
+        *  Same checks as create_message
+        */
+        #[ink(message, payable)]
+        pub fn create_private_message(&mut self, message: String) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
 
-            Ok(())
+            let mut private: Message = Message::new(caller, message, Self::env().block_timestamp());
+            private.private = true;
+
+            self.insert_message(private).map(|_| ())
         }
 
-        /* Public function - Delete caller message
-        *  Check if message has already been created by sender and not deleted
+        /* Public function - Get messages with a tag
+        *  Deleted, private and expired messages are excluded
         */
         #[ink(message)]
-        pub fn delete_message(&mut self) -> Result<(), CrudError> {
-            let caller: AccountId = self.env().caller();
+        pub fn get_messages_by_tag(&self, tag: String) -> Vec<Message> {
+            let now: Timestamp = self.env().block_timestamp();
 
-            /* Verify if message has already been created by sender */
-            self.can_edit_message(caller)?;
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now) && !m.private && m.tags.contains(&tag))
+                .collect()
+        }
 
-            /* Delete message */
-            self.get_caller_mut_message(caller).unwrap().delete(Self::env().block_timestamp());
+        /* Public function - Get replies to a sender
+        *  Deleted, private and expired replies are excluded
+        */
+        #[ink(message)]
+        pub fn get_replies_to(&self, sender: AccountId) -> Vec<Message> {
+            let now: Timestamp = self.env().block_timestamp();
 
-            Ok(())
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now) && !m.private && m.reply_to == Some(sender))
+                .collect()
         }
 
-        /* Public function - Get senders
-        * Get senders from all readable messages
-        * Maxi Bonus feature
+        /* Public function - Return a message from sender
+        *  Check sender has not deleted message in storage
+        *  MessageWasDeleted if the sender latest message is deleted, AnyMessageFound if there is none
+        *  Private messages are only returned to their sender and the creator
         */
         #[ink(message)]
-        pub fn get_senders(&self) -> Vec<AccountId> {
-            let mut senders: Vec<AccountId> = Vec::<AccountId>::new();
+        pub fn read_message_from(&self, caller: AccountId) -> Result<String, CrudError> {
 
-            for m in self.messages.clone() {
-                if m.deleted_at.is_none() {
-                    senders.push(m.sender);
-                }
+            let caller_mesage: Option<Message> = self.get_readable_message(caller);
+
+            /* Verify if sender has not deleted message in storage and it has not expired */
+            match caller_mesage {
+                Some(m) => Ok(m.message),
+                None => match self.get_caller_latest_message(caller) {
+                    Some(latest) if !latest.is_active() => Err(CrudError::MessageWasDeleted),
+                    _ => Err(CrudError::AnyMessageFound),
+                },
             }
+        }
 
-            senders.sort();
-            senders.dedup();
+        /* Public function - Return the messages of several senders
+        *  Senders without a message not deleted are paired with None
+        *  Only the first MAX_PAGE_SIZE senders are read
+        */
+        #[ink(message)]
+        pub fn read_messages_from_many(&self, senders: Vec<AccountId>) -> Vec<(AccountId, Option<String>)> {
+            senders
+                .into_iter()
+                .take(MAX_PAGE_SIZE as usize)
+                .map(|sender: AccountId| (sender, self.get_readable_message(sender).map(|m: Message| m.message)))
+                .collect()
+        }
+
+        /* Public function - Return the full message from sender, metadata included
+        *  Timestamps and counters are part of the returned Message
+        *  Check sender has not deleted message in storage, use get_deleted_by for deletion attribution
+        */
+        #[ink(message)]
+        pub fn read_full_message_from(&self, sender: AccountId) -> Result<Message, CrudError> {
+            self.get_readable_message(sender).ok_or(CrudError::AnyMessageFound)
+        }
 
-            return senders;
+        /* Public function - Return the most recent message from sender
+        *  Deleted messages are considered and returned with their deleted_at
+        *  Private messages are only returned to their sender and the creator
+        */
+        #[ink(message)]
+        pub fn read_latest_from(&self, sender: AccountId) -> Result<Message, CrudError> {
+            self.get_caller_latest_message(sender).ok_or(CrudError::AnyMessageFound)
         }
 
-        // Private function to check if caller is authorized
-        fn is_authorized(&self, caller: AccountId) -> Result<(), CrudError> {
-            if caller != self.creator {
+        /* Public function - Return a message by its id
+        *  Deleted messages are returned with their deleted_at
+        *  Private messages are only returned to their sender and the creator
+        */
+        #[ink(message)]
+        pub fn get_message_by_id(&self, id: u64) -> Result<Message, CrudError> {
+            self.stored_messages()
+                .find(|m: &Message| m.id == id)
+                .filter(|m: &Message| self.is_visible(m))
+                .ok_or(CrudError::AnyMessageFound)
+        }
+
+        /* Public function - Return a message by its storage index
+        *  Deleted messages are returned with their deleted_at
+        *  Indexes are shifted by purge_deleted, use get_message_by_id for a stable reference
+        *  Private messages are only returned to their sender and the creator, Unauthorized otherwise
+        */
+        #[ink(message)]
+        pub fn read_by_index(&self, index: u32) -> Result<Message, CrudError> {
+            let message: Message = self.messages.get(index).ok_or(CrudError::IndexOutOfBounds)?;
+
+            /* Verify if caller can see the message */
+            if !self.is_visible(&message) {
                 return Err(CrudError::Unauthorized);
             }
-            Ok(())
+
+            Ok(message)
         }
 
-        // Private function to return Result CrudError if message is too short
-        fn is_message_too_short(&self, message: &String) -> Result<(), CrudError> {
-            if message.len() < 10 {
-                return Err(CrudError::MessageTooShort);
+        /* Public function - Return the sender of a message by its storage index
+        *  Deleted messages keep their sender
+        *  Same visibility checks as read_by_index
+        */
+        #[ink(message)]
+        pub fn message_owner(&self, index: u32) -> Result<AccountId, CrudError> {
+            self.read_by_index(index).map(|m: Message| m.sender)
+        }
+
+        /* Public function - Return caller message
+        *  Check caller has not deleted message in storage
+        */
+        #[ink(message)]
+        pub fn read_my_message(&self) -> Result<String, CrudError> {
+            self.read_message_from(self.env().caller())
+        }
+
+        /* Public function - Check if an account has a message not deleted and not expired */
+        #[ink(message)]
+        pub fn has_message(&self, who: AccountId) -> bool {
+            self.is_sender_readable(who)
+        }
+
+        /* Public function - Check if an account has a deleted message */
+        #[ink(message)]
+        pub fn has_deleted_message(&self, who: AccountId) -> bool {
+            self.stored_messages().any(|m: Message| m.sender == who && !m.is_active())
+        }
+
+        /* Public function - Return (created_at, updated_at, deleted_at) of sender message
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn get_message_timestamps(&self, sender: AccountId) -> Result<(Timestamp, Timestamp, Option<Timestamp>), CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok((m.created_at, m.updated_at, m.deleted_at)),
+                None => Err(CrudError::AnyMessageFound),
             }
-            Ok(())
         }
 
-        // Private function to return Result CrudError if caller has message can be updated
-        fn can_edit_message(&self, caller: AccountId) -> Result<(), CrudError> {
-            if self.get_caller_message(caller).is_some() {
-                return Ok(())
-            } else {
-                return Err(CrudError::AnyMessageFound);
+        /* Public function - Return (created_at, updated_at, deleted_at) of sender latest message
+        *  Deleted messages are considered
+        */
+        #[ink(message)]
+        pub fn get_latest_message_timestamps(&self, sender: AccountId) -> Result<(Timestamp, Timestamp, Option<Timestamp>), CrudError> {
+            match self.get_caller_latest_index(sender).and_then(|index: u32| self.messages.get(index)).filter(|m: &Message| self.is_visible(m)) {
+                Some(m) => Ok((m.created_at, m.updated_at, m.deleted_at)),
+                None => Err(CrudError::AnyMessageFound),
             }
         }
 
-        // Private function to return Result CrudError if caller can create message
-        fn can_create_message(&self, caller: AccountId) -> Result<(), CrudError> {
-            if self.get_caller_message(caller).is_none() {
-                return Ok(())
-            } else {
-                return Err(CrudError::MessageAlreadyCreatedBySender);
+        /* Public function - Return who deleted sender latest message
+        *  Deleted messages are considered, None if the latest message is not deleted
+        *  A sender deleting its own message is returned as deleted_by, a moderation returns the creator or moderator
+        */
+        #[ink(message)]
+        pub fn get_deleted_by(&self, sender: AccountId) -> Result<Option<AccountId>, CrudError> {
+            match self.get_caller_latest_message(sender) {
+                Some(m) => Ok(m.deleted_by),
+                None => Err(CrudError::AnyMessageFound),
             }
         }
 
-        // Private fonction to get all messages from storage
-        fn get_all_messages_from_storage(&self) -> Vec<Message> {
-            let mut all_messages: Vec<Message> = Vec::<Message>::new();
+        /* Public function - Return the number of whitespace-separated words in sender message
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn word_count(&self, sender: AccountId) -> Result<u32, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.message.split_whitespace().count() as u32),
+                None => Err(CrudError::AnyMessageFound),
+            }
+        }
 
-            for m in self.messages.clone() {
-                all_messages.push(m);
+        /* Public function - Return the number of characters (not bytes) in sender message
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn message_length(&self, sender: AccountId) -> Result<u32, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.message.chars().count() as u32),
+                None => Err(CrudError::AnyMessageFound),
             }
+        }
 
-            all_messages.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        /* Public function - Return how many times sender message has been edited
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn get_edit_count(&self, sender: AccountId) -> Result<u32, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.edit_count),
+                None => Err(CrudError::AnyMessageFound),
+            }
+        }
 
-            return all_messages;
+        /* Public function - Return previous versions of sender message, only the last MAX_HISTORY are kept
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn get_message_history(&self, sender: AccountId) -> Result<Vec<(String, Timestamp)>, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.history),
+                None => Err(CrudError::AnyMessageFound),
+            }
         }
 
-        /* Private function to get caller mutable message 
-        * Latest, not deleted message
+        /* Public function - Read all messages
+        *  Check if caller is contract creator, unless public_read_all is enabled
+        *  Private messages of other senders are excluded unless caller is contract creator
         */
-        fn get_caller_mut_message(&mut self, caller: AccountId) -> Option<&mut Message> {
-            return self.messages.iter_mut().find(|m: &&mut Message| m.sender == caller && m.deleted_at.is_none());
+        #[ink(message)]
+        pub fn read_all_messages(&self) -> Result<Vec<Message>, CrudError> {
+            self.read_all_messages_sorted(false)
         }
 
-        /* Private function to get caller message 
-        * Latest, not deleted message
+        /* Public function - Read all messages sorted by created_at
+        *  Check if caller is contract creator, unless public_read_all is enabled
+        *  Private messages of other senders are excluded unless caller is contract creator
         */
-        fn get_caller_message(&self, caller: AccountId) -> Option<Message> {
-            return self.messages.iter().find(|m: &&Message| m.sender == caller && m.deleted_at.is_none()).cloned();
+        #[ink(message)]
+        pub fn read_all_messages_sorted(&self, ascending: bool) -> Result<Vec<Message>, CrudError> {
+
+            /* Verify if caller can read all messages */
+            self.can_read_all(self.env().caller())?;
+
+            let mut all_messages: Vec<Message> = self.get_all_messages_from_storage()
+                .into_iter()
+                .filter(|m: &Message| self.is_visible(m))
+                .collect();
+
+            /* Verify if messages is empty */
+            if all_messages.is_empty() {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            if ascending {
+                all_messages.reverse();
+            }
+
+            Ok(all_messages)
         }
 
+        /* Public function - Read all messages grouped by sender
+        *  Check if caller is contract creator
+        *  Groups are sorted by sender, messages in a group by created_at (oldest first)
+        */
+        #[ink(message)]
+        pub fn messages_grouped(&self) -> Result<Vec<(AccountId, Vec<Message>)>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let mut all_messages: Vec<Message> = self.get_all_messages_from_storage();
+            all_messages.reverse();
+
+            let groups: Vec<(AccountId, Vec<Message>)> = self.get_senders_from_storage(true)
+                .into_iter()
+                .map(|sender: AccountId| {
+                    let messages: Vec<Message> = all_messages
+                        .iter()
+                        .filter(|m: &&Message| m.sender == sender)
+                        .cloned()
+                        .collect();
+                    (sender, messages)
+                })
+                .collect();
+
+            Ok(groups)
+        }
+
+        /* Public function - Read all messages, optionally excluding deleted ones
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn read_all_messages_filtered(&self, include_deleted: bool) -> Result<Vec<Message>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let messages: Vec<Message> = self.get_all_messages_from_storage()
+                .into_iter()
+                .filter(|m: &Message| include_deleted || m.is_active())
+                .collect();
+
+            /* Verify if messages is empty */
+            if messages.is_empty() {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            Ok(messages)
+        }
+
+        /* Public function - Export all messages as compact tuples
+        *  Check if caller is contract creator
+        *  Deleted messages are included, in storage order
+        */
+        #[ink(message)]
+        pub fn export(&self) -> Result<Vec<ExportedMessage>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            Ok(self.stored_messages()
+                .map(|m: Message| (m.sender, m.message, m.created_at, m.updated_at, m.deleted_at))
+                .collect())
+        }
+
+        /* Public function - Read deleted messages, latest deletion first
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn read_deleted_messages(&self) -> Result<Vec<Message>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let mut deleted_messages: Vec<Message> = self.stored_messages()
+                .filter(|m: &Message| !m.is_active())
+                .collect();
+
+            /* Verify if messages is empty */
+            if deleted_messages.is_empty() {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            deleted_messages.sort_by_key(|m: &Message| core::cmp::Reverse(m.deleted_at));
+
+            Ok(deleted_messages)
+        }
+
+        /* Public function - Read all active messages
+        *  Deleted, private and expired messages are excluded
+        */
+        #[ink(message)]
+        pub fn read_active_messages(&self) -> Vec<Message> {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.get_all_messages_from_storage()
+                .into_iter()
+                .filter(|m: &Message| m.is_readable(now) && !m.private)
+                .collect()
+        }
+
+        /* Public function - Read active messages created strictly after since, oldest first
+        *  Deleted, private and expired messages are excluded
+        */
+        #[ink(message)]
+        pub fn read_messages_since(&self, since: Timestamp) -> Vec<Message> {
+            let mut messages: Vec<Message> = self.read_active_messages()
+                .into_iter()
+                .filter(|m: &Message| m.created_at > since)
+                .collect();
+
+            messages.reverse();
+
+            messages
+        }
+
+        /* Public function - Read the hottest messages, ranked by likes divided by age
+        *  Deleted, private and expired messages are excluded, expiry is checked at the block timestamp
+        *  Now is only used to compute the age, which is at least 1 millisecond, ties are ranked latest first
+        *  Limit is capped at MAX_PAGE_SIZE
+        */
+        #[ink(message)]
+        pub fn hot_messages(&self, now: Timestamp, limit: u32) -> Vec<Message> {
+            let limit: u32 = limit.min(MAX_PAGE_SIZE);
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+
+            let mut hot: Vec<Message> = self.get_all_messages_from_storage()
+                .into_iter()
+                .filter(|m: &Message| m.is_readable(block_timestamp) && !m.private)
+                .collect();
+
+            // Compare likes_a / age_a with likes_b / age_b without division
+            let age = |m: &Message| -> u128 { now.saturating_sub(m.created_at).max(1) as u128 };
+            hot.sort_by(|a: &Message, b: &Message| (b.likes as u128 * age(a)).cmp(&(a.likes as u128 * age(b))));
+
+            hot.truncate(limit as usize);
+
+            hot
+        }
+
+        /* Public function - Read a page of messages
+        *  Check if caller is contract creator
+        *  Limit is capped at MAX_PAGE_SIZE, an offset past the end returns an empty page
+        */
+        #[ink(message)]
+        pub fn read_messages_paged(&self, offset: u32, limit: u32) -> Result<Vec<Message>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let limit: u32 = limit.min(MAX_PAGE_SIZE);
+
+            let page: Vec<Message> = self.get_all_messages_from_storage()
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect();
+
+            Ok(page)
+        }
+
+        /* Public function - Read messages created between two timestamps (inclusive)
+        *  Check if caller is contract creator
+        *  An inverted range returns an empty list, an empty range returns AnyMessageFound
+        */
+        #[ink(message)]
+        pub fn read_messages_between(&self, from: Timestamp, to: Timestamp) -> Result<Vec<Message>, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            if from > to {
+                return Ok(Vec::<Message>::new());
+            }
+
+            let messages: Vec<Message> = self.get_all_messages_from_storage()
+                .into_iter()
+                .filter(|m: &Message| m.is_active() && m.created_at >= from && m.created_at <= to)
+                .collect();
+
+            /* Verify if messages is empty */
+            if messages.is_empty() {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            Ok(messages)
+        }
+
+        /* Public function - Opt in or out of public readability of caller messages */
+        #[ink(message)]
+        pub fn set_public(&mut self, public: bool) {
+            let caller: AccountId = self.env().caller();
+
+            if public {
+                self.public_senders.insert(caller, &true);
+            } else {
+                self.public_senders.remove(caller);
+            }
+        }
+
+        /* Public function - Read messages of senders who opted into public readability
+        *  Deleted and private messages are excluded
+        */
+        #[ink(message)]
+        pub fn read_public_messages(&self) -> Vec<Message> {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now) && !m.private && self.public_senders.get(m.sender).unwrap_or(false))
+                .collect()
+        }
+
+        /* Public function - Search messages containing a substring
+        *  Case-sensitive, deleted, private and expired messages are excluded
+        *  Result is capped at MAX_PAGE_SIZE messages
+        */
+        #[ink(message)]
+        pub fn search_messages(&self, needle: String) -> Vec<Message> {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now) && !m.private && m.message.contains(needle.as_str()))
+                .take(MAX_PAGE_SIZE as usize)
+                .collect()
+        }
+
+        /* Public function - Update caller message
+        *  Check if message has already been created by sender and not deleted
+        *  Check if message has the minimal length
+        *  Check if message does not exceed the maximal length
+        *  Check if last message is identical
+        */
+        #[ink(message)]
+        pub fn update_message(&mut self, message: String) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message has already been created by sender */
+            self.can_edit_message(caller)?;
+
+            let index: u32 = self.active_messages.get(caller).unwrap();
+
+            self.update_message_by_index(caller, index, message)
+        }
+
+        /* Public function - Update one of the caller messages by its index
+        *  Check if message at index belongs to caller and is not deleted
+        *  Same content checks as update_message
+        */
+        #[ink(message)]
+        pub fn update_message_at(&mut self, index: u32, message: String) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message at index belongs to caller */
+            self.can_edit_message_at(caller, index)?;
+
+            self.update_message_by_index(caller, index, message)
+        }
+
+        /* Public function - Lock caller message against updates
+        *  Check if message has already been created by sender and not deleted
+        */
+        #[ink(message)]
+        pub fn lock_my_message(&mut self) -> Result<(), CrudError> {
+            self.set_my_message_locked(true)
+        }
+
+        /* Public function - Unlock caller message
+        *  Check if message has already been created by sender and not deleted
+        */
+        #[ink(message)]
+        pub fn unlock_my_message(&mut self) -> Result<(), CrudError> {
+            self.set_my_message_locked(false)
+        }
+
+        /* Public function - Create caller message, or update it if one already exists
+        *  Same checks as create_message or update_message
+        *  Check if no value is transferred when updating, only creates are charged
+        */
+        #[ink(message, payable)]
+        pub fn upsert_message(&mut self, message: String) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* An expired message frees the slot, so a new message is created */
+            if self.is_sender_readable(caller) {
+
+                /* Verify if no value is transferred with the update */
+                if self.env().transferred_value() > 0 {
+                    return Err(CrudError::UnexpectedPayment);
+                }
+
+                self.update_message(message)
+            } else {
+                self.create_message(message)
+            }
+        }
+
+        /* Public function - Delete caller message
+        *  Check if message has already been created by sender and not deleted
+        */
+        #[ink(message)]
+        pub fn delete_message(&mut self) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message has already been created by sender */
+            self.can_edit_message(caller)?;
+
+            let index: u32 = self.active_messages.get(caller).unwrap();
+
+            self.delete_message_by_index(caller, index, caller);
+
+            Ok(())
+        }
+
+        /* Public function - Delete one of the caller messages by its index
+        *  Check if message at index belongs to caller and is not deleted
+        */
+        #[ink(message)]
+        pub fn delete_message_at(&mut self, index: u32) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message at index belongs to caller */
+            self.can_edit_message_at(caller, index)?;
+
+            self.delete_message_by_index(caller, index, caller);
+
+            Ok(())
+        }
+
+        /* Public function - Transfer contract ownership
+        *  Check if caller is contract creator
+        *  Check if new owner is not the zero address
+        */
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if new owner is not the zero address */
+            Self::reject_zero(new_owner)?;
+
+            self.creator = new_owner;
+            self.pending_owner = None;
+
+            Ok(())
+        }
+
+        /* Public function - Propose a new contract owner
+        *  Check if caller is contract creator
+        *  Check if new owner is not the zero address
+        *  Ownership moves once the new owner calls accept_ownership
+        */
+        #[ink(message)]
+        pub fn propose_new_owner(&mut self, new_owner: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if new owner is not the zero address */
+            Self::reject_zero(new_owner)?;
+
+            self.pending_owner = Some(new_owner);
+
+            Ok(())
+        }
+
+        /* Public function - Accept a pending ownership transfer
+        *  Check if caller is the proposed owner
+        */
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if caller is the proposed owner */
+            if self.pending_owner != Some(caller) {
+                return Err(CrudError::Unauthorized);
+            }
+
+            self.creator = caller;
+            self.pending_owner = None;
+
+            Ok(())
+        }
+
+        /* Public function - Cancel a pending ownership transfer
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn cancel_ownership_transfer(&mut self) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.pending_owner = None;
+
+            Ok(())
+        }
+
+        /* Public function - Get the proposed owner, if any */
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner
+        }
+
+        /* Public function - Add a moderator
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn add_moderator(&mut self, who: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if moderator is not the zero address */
+            Self::reject_zero(who)?;
+
+            if !self.moderators.contains(&who) {
+                self.moderators.push(who);
+            }
+
+            Ok(())
+        }
+
+        /* Public function - Remove a moderator
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn remove_moderator(&mut self, who: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.moderators.retain(|m: &AccountId| *m != who);
+
+            Ok(())
+        }
+
+        /* Public function - Get moderator state of an account */
+        #[ink(message)]
+        pub fn is_moderator(&self, who: AccountId) -> bool {
+            self.moderators.contains(&who)
+        }
+
+        /* Public function - Get contract owner */
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.creator
+        }
+
+        /* Public function - Get contract creator, same account as owner */
+        #[ink(message)]
+        pub fn creator(&self) -> AccountId {
+            self.creator
+        }
+
+        /* Public function - Comment on target message
+        *  Check if target has a message not deleted
+        *  Check if comment is not blank
+        *  Check if comment has the minimal length
+        *  Check if comment does not exceed the maximal length
+        *  Check if comment does not contain a banned word
+        *  Comments are dropped when the target active message changes
+        */
+        #[ink(message)]
+        pub fn add_comment(&mut self, target: AccountId, comment: String) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender is not blocked */
+            self.is_sender_blocked(caller)?;
+
+            /* Verify if target has a message */
+            let index: u32 = self.active_messages.get(target).ok_or(CrudError::AnyMessageFound)?;
+
+            /* Verify if comment is not blank */
+            self.is_message_blank(&comment)?;
+
+            /* Verify if comment length is between minimal and maximal length */
+            self.is_message_length_valid(&comment)?;
+
+            /* Verify if comment does not contain a banned word */
+            self.contains_banned_word(&comment)?;
+
+            let mut comments: Vec<Comment> = self.comments.get(target).unwrap_or_default();
+            comments.push((caller, comment, Self::env().block_timestamp()));
+            self.comments.insert(target, &comments);
+
+            let mut commented: Message = self.messages.get(index).unwrap();
+            commented.comment_count = commented.comment_count.saturating_add(1);
+            self.messages.insert(index, &commented);
+
+            Ok(())
+        }
+
+        /* Public function - Get comments on target message */
+        #[ink(message)]
+        pub fn get_comments(&self, target: AccountId) -> Vec<Comment> {
+            self.comments.get(target).unwrap_or_default()
+        }
+
+        /* Public function - React to sender message with a reaction such as an emoji
+        *  Check if sender has a message not deleted
+        *  Check if reaction is not empty and at most MAX_REACTION_LENGTH bytes
+        *  Check if a new reaction does not exceed MAX_REACTIONS distinct reactions on the message
+        */
+        #[ink(message)]
+        pub fn react(&mut self, sender: AccountId, emoji: String) -> Result<(), CrudError> {
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender has a message */
+            let index: u32 = self.active_messages.get(sender).ok_or(CrudError::AnyMessageFound)?;
+
+            /* Verify if reaction length is valid */
+            if emoji.is_empty() || emoji.len() > MAX_REACTION_LENGTH {
+                return Err(CrudError::InvalidReaction);
+            }
+
+            let key: (u32, String) = (index, emoji.clone());
+
+            match self.reactions.get(&key) {
+                Some(count) => {
+                    self.reactions.insert(&key, &count.saturating_add(1));
+                }
+                None => {
+                    let mut keys: Vec<String> = self.reaction_keys.get(index).unwrap_or_default();
+
+                    /* Verify if message has room for a new reaction */
+                    if keys.len() >= MAX_REACTIONS {
+                        return Err(CrudError::InvalidReaction);
+                    }
+
+                    keys.push(emoji);
+                    self.reaction_keys.insert(index, &keys);
+                    self.reactions.insert(&key, &1);
+                }
+            }
+
+            Ok(())
+        }
+
+        /* Public function - Get reactions on sender message with their counts
+        *  Reactions are returned in the order they were first used
+        */
+        #[ink(message)]
+        pub fn get_reactions(&self, sender: AccountId) -> Vec<(String, u32)> {
+            let index: u32 = match self.active_messages.get(sender) {
+                Some(index) => index,
+                None => return Vec::<(String, u32)>::new(),
+            };
+
+            self.reaction_keys
+                .get(index)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|emoji: String| {
+                    let count: u32 = self.reactions.get((index, emoji.clone())).unwrap_or(0);
+                    (emoji, count)
+                })
+                .collect()
+        }
+
+        /* Public function - Like sender message
+        *  Check if sender has a message not deleted
+        *  Check if caller has not already liked it
+        */
+        #[ink(message)]
+        pub fn like_message(&mut self, sender: AccountId) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender has a message */
+            let index: u32 = self.active_messages.get(sender).ok_or(CrudError::AnyMessageFound)?;
+
+            let mut liked: Message = self.messages.get(index).unwrap();
+
+            /* Verify if caller has not already liked message */
+            if liked.likers.contains(&caller) {
+                return Err(CrudError::AlreadyLiked);
+            }
+
+            // Like message using struct method
+            liked.like(caller);
+            self.messages.insert(index, &liked);
+
+            self.env().emit_event(MessageLiked { liker: caller, sender, likes: liked.likes });
+
+            Ok(())
+        }
+
+        /* Public function - Like the messages of several senders
+        *  Each like is checked like like_message, one failure does not abort the others
+        *  Only the first MAX_PAGE_SIZE senders are liked, the others get BatchTooLarge
+        */
+        #[ink(message)]
+        pub fn like_many(&mut self, senders: Vec<AccountId>) -> Vec<(AccountId, Result<(), CrudError>)> {
+            senders
+                .into_iter()
+                .enumerate()
+                .map(|(position, sender): (usize, AccountId)| {
+                    if position < MAX_PAGE_SIZE as usize {
+                        (sender, self.like_message(sender))
+                    } else {
+                        (sender, Err(CrudError::BatchTooLarge))
+                    }
+                })
+                .collect()
+        }
+
+        /* Public function - Return the like count of sender message
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn get_likes(&self, sender: AccountId) -> Result<u32, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.likes),
+                None => Err(CrudError::AnyMessageFound),
+            }
+        }
+
+        /* Public function - Sum likes of all readable messages
+        * Computed in a single pass over messages, saturates at u32::MAX
+        */
+        #[ink(message)]
+        pub fn total_likes(&self) -> u32 {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now))
+                .fold(0, |total: u32, m: Message| total.saturating_add(m.likes))
+        }
+
+        /* Public function - Block a sender from creating or updating messages
+        *  Check if caller is contract creator or moderator
+        */
+        #[ink(message)]
+        pub fn block_sender(&mut self, who: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner or moderator */
+            self.can_moderate(self.env().caller())?;
+
+            /* Verify if blocked sender is not the zero address */
+            Self::reject_zero(who)?;
+
+            if !self.blocked.contains(&who) {
+                self.blocked.push(who);
+            }
+
+            Ok(())
+        }
+
+        /* Public function - Unblock a sender
+        *  Check if caller is contract creator or moderator
+        */
+        #[ink(message)]
+        pub fn unblock_sender(&mut self, who: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner or moderator */
+            self.can_moderate(self.env().caller())?;
+
+            self.blocked.retain(|b: &AccountId| *b != who);
+
+            Ok(())
+        }
+
+        /* Public function - Get blocked state of a sender */
+        #[ink(message)]
+        pub fn is_blocked(&self, who: AccountId) -> bool {
+            self.blocked.contains(&who)
+        }
+
+        /* Public function - Remove every message except the genesis message
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn clear_all(&mut self) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.retain_messages(|index: u32, _: &Message| index == 0);
+
+            Ok(())
+        }
+
+        /* Public function - Pin the message of a sender
+        *  Check if caller is contract creator
+        *  Check if sender has a message not deleted
+        */
+        #[ink(message)]
+        pub fn pin_message(&mut self, sender: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if sender has a message */
+            let index: u32 = self.active_messages.get(sender).ok_or(CrudError::AnyMessageFound)?;
+
+            self.pinned = Some(index);
+
+            Ok(())
+        }
+
+        /* Public function - Unpin the pinned message
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn unpin(&mut self) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.pinned = None;
+
+            Ok(())
+        }
+
+        /* Public function - Get the pinned message
+        *  Return None if nothing is pinned or the pinned message has been deleted or has expired
+        *  A private pinned message is only returned to its sender and the contract creator
+        */
+        #[ink(message)]
+        pub fn get_pinned(&self) -> Option<Message> {
+            let now: Timestamp = self.env().block_timestamp();
+            let index: u32 = self.pinned?;
+
+            self.messages.get(index).filter(|m: &Message| m.is_readable(now) && self.is_visible(m))
+        }
+
+        /* Public function - Seed messages on behalf of other senders
+        *  Check if caller is contract creator
+        *  Zero address entries and entries failing create_message checks are skipped, the create fee is not charged
+        *  Return the number of inserted messages
+        */
+        #[ink(message)]
+        pub fn batch_seed(&mut self, entries: Vec<(AccountId, String)>) -> Result<u32, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let created_at: Timestamp = Self::env().block_timestamp();
+            let mut inserted: u32 = 0;
+
+            for (sender, message) in entries {
+                if Self::reject_zero(sender).is_err() {
+                    continue;
+                }
+
+                if self.insert_message_with(Message::new(sender, message, created_at), false, false).is_ok() {
+                    inserted += 1;
+                }
+            }
+
+            Ok(inserted)
+        }
+
+        /* Public function - Import a message on behalf of sender with its original creation timestamp
+        *  Check if caller is contract creator
+        *  Check if sender is not the zero address
+        *  Fee, one message per sender and rate limit checks are skipped, other create_message checks apply
+        *  The imported message replaces the sender active message only if it is not older
+        *  Imports do not change last_activity nor the sender cooldown
+        */
+        #[ink(message)]
+        pub fn admin_create_at(&mut self, sender: AccountId, message: String, created_at: Timestamp) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if sender is not the zero address */
+            Self::reject_zero(sender)?;
+
+            self.insert_message_with(Message::new(sender, message, created_at), true, false).map(|_| ())
+        }
+
+        /* Public function - Permanently remove deleted messages
+        *  Check if caller is contract creator
+        *  Genesis message is always kept, even if deleted
+        *  Return the number of purged messages
+        */
+        #[ink(message)]
+        pub fn purge_deleted(&mut self) -> Result<u32, CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            Ok(self.retain_messages(|index: u32, m: &Message| index == 0 || m.is_active()))
+        }
+
+        /* Public function - Permanently remove every message of a sender
+        *  Check if caller is contract creator
+        *  Genesis message is always kept, it is deleted if it belongs to sender
+        *  Comments, likes and votes of sender on other messages are removed
+        *  Indexes of following messages are shifted, use ids for a stable reference
+        *  Return the number of purged messages
+        */
+        #[ink(message)]
+        pub fn purge_sender(&mut self, sender: AccountId) -> Result<u32, CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(caller)?;
+
+            Ok(self.erase_sender(sender, caller))
+        }
+
+        /* Public function - Withdraw collected fees to the creator
+        *  Check if caller is contract creator
+        *  Accrued fees are reset before the transfer and restored if it fails
+        */
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let amount: Balance = self.accrued_fees;
+            self.accrued_fees = 0;
+
+            if self.env().transfer(self.creator, amount).is_err() {
+                self.accrued_fees = amount;
+                return Err(CrudError::TransferFailed);
+            }
+
+            Ok(())
+        }
+
+        /* Public function - Get the fees collected and not withdrawn yet */
+        #[ink(message)]
+        pub fn accrued_fees(&self) -> Balance {
+            self.accrued_fees
+        }
+
+        /* Public function - Get the fee required to create a message */
+        #[ink(message)]
+        pub fn create_fee(&self) -> Balance {
+            self.create_fee
+        }
+
+        /* Public function - Terminate the contract and refund its balance to the creator
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn terminate(&mut self) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.env().terminate_contract(self.creator)
+        }
+
+        /* Public function - Upvote sender message
+        *  Check if sender has a message not deleted
+        *  Check if caller has not already voted for it
+        */
+        #[ink(message)]
+        pub fn upvote_message(&mut self, sender: AccountId) -> Result<(), CrudError> {
+            self.vote_message(sender, true)
+        }
+
+        /* Public function - Downvote sender message
+        *  Check if sender has a message not deleted
+        *  Check if caller has not already voted for it
+        */
+        #[ink(message)]
+        pub fn downvote_message(&mut self, sender: AccountId) -> Result<(), CrudError> {
+            self.vote_message(sender, false)
+        }
+
+        /* Public function - Return upvotes minus downvotes of sender message
+        *  Check sender has not deleted message in storage and it has not expired
+        */
+        #[ink(message)]
+        pub fn get_score(&self, sender: AccountId) -> Result<i64, CrudError> {
+            match self.get_readable_message(sender) {
+                Some(m) => Ok(m.upvotes as i64 - m.downvotes as i64),
+                None => Err(CrudError::AnyMessageFound),
+            }
+        }
+
+        /* Public function - Replace the contract code, keeping its storage
+        *  Check if caller is contract creator
+        *  New code must keep the CrudContract storage layout
+        */
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: Hash) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.env().set_code_hash(&code_hash).map_err(|_| CrudError::UpgradeFailed)
+        }
+
+        /* Public function - Ban a word from created and updated messages
+        *  Check if caller is contract creator
+        *  Check if word is not blank
+        *  Check if banned words list is not full
+        */
+        #[ink(message)]
+        pub fn add_banned_word(&mut self, word: String) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            /* Verify if word is not blank */
+            self.is_message_blank(&word)?;
+
+            let word: String = word.to_lowercase();
+            if self.banned_words.contains(&word) {
+                return Ok(());
+            }
+
+            /* Verify if banned words list is not full */
+            if self.banned_words.len() >= MAX_BANNED_WORDS {
+                return Err(CrudError::TooManyBannedWords);
+            }
+
+            self.banned_words.push(word);
+
+            Ok(())
+        }
+
+        /* Public function - Remove a banned word
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn remove_banned_word(&mut self, word: String) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            let word: String = word.to_lowercase();
+            self.banned_words.retain(|w: &String| *w != word);
+
+            Ok(())
+        }
+
+        /* Public function - Allow or disallow any caller to read all messages
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn set_public_read_all(&mut self, enabled: bool) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.public_read_all = enabled;
+
+            Ok(())
+        }
+
+        /* Public function - Check if any caller can read all messages */
+        #[ink(message)]
+        pub fn is_public_read_all(&self) -> bool {
+            self.public_read_all
+        }
+
+        /* Public function - Set the welcome text, an empty text clears it
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn set_welcome(&mut self, text: String) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.welcome_text = if text.is_empty() { None } else { Some(text) };
+
+            Ok(())
+        }
+
+        /* Public function - Get the welcome text */
+        #[ink(message)]
+        pub fn get_welcome(&self) -> Option<String> {
+            self.welcome_text.clone()
+        }
+
+        /* Public function - Pause or unpause writes
+        *  Check if caller is contract creator
+        */
+        #[ink(message)]
+        pub fn set_paused(&mut self, paused: bool) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner */
+            self.is_authorized(self.env().caller())?;
+
+            self.paused = paused;
+
+            Ok(())
+        }
+
+        /* Public function - Get paused state */
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /* Public function - Delete all caller messages
+        *  Check if caller has at least one message not deleted
+        *  Return the number of deleted messages
+        */
+        #[ink(message)]
+        pub fn delete_all_my_messages(&mut self) -> Result<u32, CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message has already been created by sender */
+            self.can_edit_message(caller)?;
+
+            let indexes: Vec<u32> = self.indexed_messages()
+                .filter(|(_, m): &(u32, Message)| m.sender == caller && m.is_active())
+                .map(|(index, _): (u32, Message)| index)
+                .collect();
+
+            for index in indexes.iter() {
+                self.delete_message_by_index(caller, *index, caller);
+            }
+
+            Ok(indexes.len() as u32)
+        }
+
+        /* Public function - Permanently remove every caller message
+        *  Check if caller has at least one message, deleted or not
+        *  Genesis message is always kept, it is deleted if it belongs to caller
+        *  Comments, likes and votes of caller on other messages are removed
+        *  Indexes of following messages are shifted, use ids for a stable reference
+        *  Return the number of removed messages
+        */
+        #[ink(message)]
+        pub fn forget_me(&mut self) -> Result<u32, CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if caller has at least one message */
+            if self.get_caller_latest_index(caller).is_none() {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            Ok(self.erase_sender(caller, caller))
+        }
+
+        /* Public function - Delete the message of any sender
+        *  Check if caller is contract creator or moderator
+        *  Check if sender has a message not deleted
+        */
+        #[ink(message)]
+        pub fn admin_delete_message(&mut self, sender: AccountId) -> Result<(), CrudError> {
+
+            /* Verify if caller is contract owner or moderator */
+            self.can_moderate(self.env().caller())?;
+
+            /* Verify if sender has a message */
+            let index: u32 = self.active_messages.get(sender).ok_or(CrudError::AnyMessageFound)?;
+
+            self.delete_message_by_index(sender, index, self.env().caller());
+
+            Ok(())
+        }
+
+        /* Public function - Transfer caller message to another account
+        *  Check if caller has a message not deleted
+        *  Check if new owner has no message yet (skipped in multiple mode)
+        */
+        #[ink(message)]
+        pub fn transfer_message(&mut self, new_owner: AccountId) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message has already been created by sender */
+            self.can_edit_message(caller)?;
+
+            /* Verify if new owner is not the zero address */
+            Self::reject_zero(new_owner)?;
+
+            /* Verify if new owner has no message */
+            if !self.allow_multiple {
+                self.can_create_message(new_owner)?;
+            }
+
+            // Transfer message using struct method
+            let index: u32 = self.active_messages.get(caller).unwrap();
+            let mut transferred: Message = self.messages.get(index).unwrap();
+            transferred.transfer(new_owner);
+            self.messages.insert(index, &transferred);
+
+            self.decrement_sender_count(caller);
+            self.increment_sender_count(new_owner);
+
+            self.refresh_active_message(caller);
+            self.refresh_active_message(new_owner);
+
+            Ok(())
+        }
+
+        /* Public function - Restore caller message
+        *  Check if caller has a message at all
+        *  Check if sender is not blocked
+        *  Check if caller latest message is deleted
+        *  Check if caller deleted it, a moderated message stays deleted
+        *  Check if caller has no other message not deleted (skipped in multiple mode)
+        */
+        #[ink(message)]
+        pub fn restore_message(&mut self) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender is not blocked */
+            self.is_sender_blocked(caller)?;
+
+            /* Verify if caller has a message */
+            let index: u32 = self.get_caller_latest_index(caller).ok_or(CrudError::AnyMessageFound)?;
+            let mut latest: Message = self.messages.get(index).unwrap();
+
+            /* Verify if latest message is deleted */
+            if latest.is_active() {
+                return Err(CrudError::MessageNotDeleted);
+            }
+
+            /* Verify if caller deleted the message */
+            if latest.deleted_by != Some(caller) {
+                return Err(CrudError::Unauthorized);
+            }
+
+            /* Verify if caller has no other message */
+            if !self.allow_multiple {
+                self.can_create_message(caller)?;
+            }
+
+            // Restore message using struct method
+            latest.restore();
+            self.messages.insert(index, &latest);
+            self.refresh_active_message(caller);
+
+            Ok(())
+        }
+
+        /* Public function - Get the minimum message length in characters */
+        #[ink(message)]
+        pub fn min_length(&self) -> u32 {
+            self.min_length
+        }
+
+        /* Public function - Get the contract version */
+        #[ink(message)]
+        pub fn version(&self) -> u32 {
+            CONTRACT_VERSION
+        }
+
+        /* Public function - Get the contract semantic version */
+        #[ink(message)]
+        pub fn semver(&self) -> String {
+            String::from(env!("CARGO_PKG_VERSION"))
+        }
+
+        /* Public function - Get the timestamp of the last successful create, update or delete */
+        #[ink(message)]
+        pub fn last_activity(&self) -> Timestamp {
+            self.last_activity
+        }
+
+        /* Public function - Get the genesis message created by the constructor
+        * Returned even if it has been deleted
+        */
+        #[ink(message)]
+        pub fn genesis_message(&self) -> Message {
+            self.messages.get(0).unwrap()
+        }
+
+        /* Public function - Get senders
+        * Get senders from all readable messages
+        * Maxi Bonus feature
+        */
+        #[ink(message)]
+        pub fn get_senders(&self) -> Vec<AccountId> {
+            self.get_senders_from_storage(false)
+        }
+
+        /* Public function - Get senders, sorted and deduplicated
+        * Senders with only deleted messages are included if include_deleted is true
+        */
+        #[ink(message)]
+        pub fn get_all_senders(&self, include_deleted: bool) -> Vec<AccountId> {
+            self.get_senders_from_storage(include_deleted)
+        }
+
+        /* Public function - Get senders whose latest readable message was created at or after since */
+        #[ink(message)]
+        pub fn senders_active_since(&self, since: Timestamp) -> Vec<AccountId> {
+            self.get_senders_from_storage(false)
+                .into_iter()
+                .filter(|sender: &AccountId| {
+                    self.get_caller_message(*sender).is_some_and(|m: Message| m.created_at >= since)
+                })
+                .collect()
+        }
+
+        /* Public function - Get senders whose readable message was last updated before now - older_than
+        * A threshold before the epoch returns no sender
+        */
+        #[ink(message)]
+        pub fn stale_senders(&self, older_than: Timestamp, now: Timestamp) -> Vec<AccountId> {
+            let threshold: Timestamp = match now.checked_sub(older_than) {
+                Some(t) => t,
+                None => return Vec::<AccountId>::new(),
+            };
+
+            self.get_senders_from_storage(false)
+                .into_iter()
+                .filter(|sender: &AccountId| {
+                    self.get_caller_message(*sender).is_some_and(|m: Message| m.updated_at < threshold)
+                })
+                .collect()
+        }
+
+        /* Public function - Check if an account is a sender of a readable message
+        * Constant cost lookup in the active_messages index, expired messages are not readable
+        */
+        #[ink(message)]
+        pub fn contains_sender(&self, who: AccountId) -> bool {
+            self.has_message(who)
+        }
+
+        /* Public function - Get a page of senders from all readable messages
+        * Limit is capped at MAX_PAGE_SIZE, an offset past the end returns an empty page
+        */
+        #[ink(message)]
+        pub fn get_senders_paged(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+            self.get_senders_paged_filtered(offset, limit, false)
+        }
+
+        /* Public function - Get a page of senders
+        * Senders with only deleted messages are included if include_deleted is true
+        * Limit is capped at MAX_PAGE_SIZE, an offset past the end returns an empty page
+        */
+        #[ink(message)]
+        pub fn get_senders_paged_filtered(&self, offset: u32, limit: u32, include_deleted: bool) -> Vec<AccountId> {
+            let limit: u32 = limit.min(MAX_PAGE_SIZE);
+
+            self.get_senders_from_storage(include_deleted)
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect()
+        }
+
+        /* Public function - Count distinct senders with a readable message
+        * Each sender of the senders index is counted if its active message has not expired
+        */
+        #[ink(message)]
+        pub fn get_sender_count(&self) -> u32 {
+            self.senders.iter().filter(|sender: &&AccountId| self.is_sender_readable(**sender)).count() as u32
+        }
+
+        /* Public function - Count messages ever created by an account
+        * Deleted messages are counted
+        */
+        #[ink(message)]
+        pub fn create_count(&self, who: AccountId) -> u32 {
+            self.create_counts.get(who).unwrap_or(0)
+        }
+
+        /* Public function - Get message statistics
+        * Computed in a single pass over messages, counts saturate at u32::MAX
+        * Expired messages are counted in total only, active matches message_count
+        */
+        #[ink(message)]
+        pub fn stats(&self) -> Stats {
+            let now: Timestamp = self.env().block_timestamp();
+            let mut stats: Stats = Stats { total: 0, active: 0, deleted: 0, unique_senders: 0 };
+
+            for (index, m) in self.indexed_messages() {
+                stats.total = stats.total.saturating_add(1);
+
+                if m.is_readable(now) {
+                    stats.active = stats.active.saturating_add(1);
+                } else if !m.is_active() {
+                    stats.deleted = stats.deleted.saturating_add(1);
+                }
+
+                if self.active_messages.get(m.sender) == Some(index) {
+                    stats.unique_senders = stats.unique_senders.saturating_add(1);
+                }
+            }
+
+            stats
+        }
+
+        /* Public function - Get the oldest and newest created_at of messages not deleted and not expired
+        * Computed in a single pass over messages, None if there is no message
+        */
+        #[ink(message)]
+        pub fn time_bounds(&self) -> Option<(Timestamp, Timestamp)> {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.stored_messages()
+                .filter(|m: &Message| m.is_readable(now))
+                .fold(None, |bounds: Option<(Timestamp, Timestamp)>, m: Message| match bounds {
+                    Some((oldest, newest)) => Some((oldest.min(m.created_at), newest.max(m.created_at))),
+                    None => Some((m.created_at, m.created_at)),
+                })
+        }
+
+        /* Public function - Count readable messages
+        * Deleted and expired messages are not counted
+        */
+        #[ink(message)]
+        pub fn message_count(&self) -> u32 {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.stored_messages().filter(|m: &Message| m.is_readable(now)).count() as u32
+        }
+
+        /* Public function - Count all messages
+        * Deleted messages are counted
+        */
+        #[ink(message)]
+        pub fn total_message_count(&self) -> u32 {
+            self.messages_len
+        }
+
+        // Private function to check if caller is authorized
+        fn is_authorized(&self, caller: AccountId) -> Result<(), CrudError> {
+            if caller != self.creator {
+                return Err(CrudError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        // Private function to check if caller can read all messages
+        fn can_read_all(&self, caller: AccountId) -> Result<(), CrudError> {
+            if self.public_read_all {
+                return Ok(());
+            }
+            self.is_authorized(caller)
+        }
+
+        // Private function to return Result CrudError if account is the zero address
+        fn reject_zero(who: AccountId) -> Result<(), CrudError> {
+            if who == AccountId::from([0u8; 32]) {
+                return Err(CrudError::ZeroAddress);
+            }
+            Ok(())
+        }
+
+        // Private function to check if caller is authorized to moderate (creator or moderator)
+        fn can_moderate(&self, caller: AccountId) -> Result<(), CrudError> {
+            if self.moderators.contains(&caller) {
+                return Ok(());
+            }
+            self.is_authorized(caller)
+        }
+
+        // Private function to return Result CrudError if contract is paused
+        fn is_contract_paused(&self) -> Result<(), CrudError> {
+            if self.paused {
+                return Err(CrudError::ContractPaused);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if sender is blocked
+        fn is_sender_blocked(&self, caller: AccountId) -> Result<(), CrudError> {
+            if self.blocked.contains(&caller) {
+                return Err(CrudError::SenderBlocked);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if messages has reached max_messages
+        fn is_storage_full(&self) -> Result<(), CrudError> {
+            if self.max_messages != 0 && self.messages_len >= self.max_messages {
+                return Err(CrudError::StorageFull);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if sender has reached its message quota, counted over create_counts so purged messages still count
+        fn is_sender_quota_exceeded(&self, caller: AccountId) -> Result<(), CrudError> {
+            if self.max_per_sender == 0 {
+                return Ok(());
+            }
+
+            if self.create_count(caller) >= self.max_per_sender {
+                return Err(CrudError::SenderQuotaExceeded);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if sender created a message less than cooldown ago
+        fn is_sender_rate_limited(&self, caller: AccountId, now: Timestamp) -> Result<(), CrudError> {
+            if self.cooldown == 0 {
+                return Ok(());
+            }
+
+            if let Some(last_create) = self.last_creates.get(caller) {
+                if now.saturating_sub(last_create) < self.cooldown {
+                    return Err(CrudError::RateLimited);
+                }
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if message is only whitespace
+        fn is_message_blank(&self, message: &str) -> Result<(), CrudError> {
+            if message.trim().is_empty() {
+                return Err(CrudError::MessageBlank);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if message contains a banned word (case-insensitive)
+        fn contains_banned_word(&self, message: &str) -> Result<(), CrudError> {
+            let message: String = message.to_lowercase();
+            if self.banned_words.iter().any(|w: &String| message.contains(w.as_str())) {
+                return Err(CrudError::ContainsBannedWord);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if message is too short
+        // Length is counted in characters (Unicode scalar values), not bytes
+        fn is_message_too_short(&self, message: &str) -> Result<(), CrudError> {
+            if message.chars().count() < self.min_length as usize {
+                return Err(CrudError::MessageTooShort);
+            }
+            Ok(())
+        }
+
+        // Private function to return Result CrudError if message is too long
+        fn is_message_too_long(&self, message: &str) -> Result<(), CrudError> {
+            if message.len() > self.max_length as usize {
+                return Err(CrudError::MessageTooLong);
+            }
+            Ok(())
+        }
+
+        // Private function to check both length bounds, MessageTooShort takes precedence over MessageTooLong
+        fn is_message_length_valid(&self, message: &str) -> Result<(), CrudError> {
+            self.is_message_too_short(message)?;
+            self.is_message_too_long(message)
+        }
+
+        // Private function to return Result CrudError if caller has message can be updated
+        fn can_edit_message(&self, caller: AccountId) -> Result<(), CrudError> {
+            if self.get_caller_message(caller).is_some() {
+                Ok(())
+            } else {
+                Err(CrudError::AnyMessageFound)
+            }
+        }
+
+        // Private function to return Result CrudError if message at index is an active message of caller
+        fn can_edit_message_at(&self, caller: AccountId, index: u32) -> Result<(), CrudError> {
+            match self.messages.get(index) {
+                Some(m) if m.is_active() => {
+                    if m.sender == caller {
+                        Ok(())
+                    } else {
+                        Err(CrudError::Unauthorized)
+                    }
+                }
+                _ => Err(CrudError::AnyMessageFound),
+            }
+        }
+
+        // Private function to return Result CrudError if caller can create message, an expired message frees the slot
+        fn can_create_message(&self, caller: AccountId) -> Result<(), CrudError> {
+            if !self.is_sender_readable(caller) {
+                Ok(())
+            } else {
+                Err(CrudError::MessageAlreadyCreatedBySender)
+            }
+        }
+
+        // Private function to get sorted and deduplicated senders, optionally including deleted and expired messages
+        fn get_senders_from_storage(&self, include_deleted: bool) -> Vec<AccountId> {
+            let mut senders: Vec<AccountId> = self.senders
+                .iter()
+                .filter(|sender: &&AccountId| include_deleted || self.is_sender_readable(**sender))
+                .copied()
+                .collect();
+
+            senders.sort();
+
+            senders
+        }
+
+        // Private fonction to get all messages from storage
+        fn get_all_messages_from_storage(&self) -> Vec<Message> {
+            let mut all_messages: Vec<Message> = self.stored_messages().collect();
+
+            // Messages created in the same block are ordered by id, latest first
+            all_messages.sort_by_key(|m: &Message| core::cmp::Reverse((m.created_at, m.id)));
+
+            all_messages
+        }
+
+        // Private function to lock or unlock caller message
+        fn set_my_message_locked(&mut self, locked: bool) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if message has already been created by sender */
+            self.can_edit_message(caller)?;
+
+            let index: u32 = self.active_messages.get(caller).unwrap();
+            let mut message: Message = self.messages.get(index).unwrap();
+            message.locked = locked;
+            self.messages.insert(index, &message);
+
+            Ok(())
+        }
+
+        // Private function to upvote or downvote sender message once per caller
+        fn vote_message(&mut self, sender: AccountId, upvote: bool) -> Result<(), CrudError> {
+            let caller: AccountId = self.env().caller();
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender has a message */
+            let index: u32 = self.active_messages.get(sender).ok_or(CrudError::AnyMessageFound)?;
+
+            let mut voted: Message = self.messages.get(index).unwrap();
+
+            /* Verify if caller has not already voted */
+            if voted.has_voted(caller) {
+                return Err(CrudError::AlreadyVoted);
+            }
+
+            // Vote using struct method
+            voted.vote(caller, upvote);
+            self.messages.insert(index, &voted);
+
+            Ok(())
+        }
+
+        /* Private function to insert a new message
+        *  Check if transferred value covers the create fee
+        *  Check if contract is not paused
+        *  Check if sender is not blocked
+        *  Check if message has already been created by sender (skipped in multiple mode)
+        *  Check if sender has not reached its message quota
+        *  Check if storage is not full
+        *  Check if sender is not rate limited
+        *  Check if message is not blank
+        *  Check if message has the minimal length
+        *  Check if message does not exceed the maximal length
+        *  Check if message does not contain a banned word
+        *  Check if message ids and indexes are not exhausted
+        *  Return the index of the inserted message
+        */
+        fn insert_message(&mut self, new_message: Message) -> Result<u32, CrudError> {
+            self.insert_message_with(new_message, false, true)
+        }
+
+        /* Private function to insert a new message
+        *  Same checks as insert_message
+        *  An import skips one message per sender and rate limit checks
+        *  An import becomes the active message only if it is not older, and leaves last_activity and last_creates untouched
+        *  The create fee is only checked when charge_fee is set, the transferred value is then added to the accrued fees
+        */
+        fn insert_message_with(&mut self, mut new_message: Message, import: bool, charge_fee: bool) -> Result<u32, CrudError> {
+            let caller: AccountId = new_message.sender;
+
+            /* Verify if transferred value covers the create fee */
+            if charge_fee && self.env().transferred_value() < self.create_fee {
+                return Err(CrudError::InsufficientFee);
+            }
+
+            /* Verify if contract is not paused */
+            self.is_contract_paused()?;
+
+            /* Verify if sender is not blocked */
+            self.is_sender_blocked(caller)?;
+
+            /* Verify if message has already been created by sender */
+            if !self.allow_multiple && !import {
+                self.can_create_message(caller)?;
+            }
+
+            /* Verify if sender has not reached its message quota */
+            self.is_sender_quota_exceeded(caller)?;
+
+            /* Verify if storage is not full */
+            self.is_storage_full()?;
+
+            /* Verify if sender is not rate limited */
+            if !import {
+                self.is_sender_rate_limited(caller, new_message.created_at)?;
+            }
+
+            /* Verify if message is not blank */
+            self.is_message_blank(&new_message.message)?;
+
+            /* Verify if message length is between minimal and maximal length */
+            self.is_message_length_valid(&new_message.message)?;
+
+            /* Verify if message does not contain a banned word */
+            self.contains_banned_word(&new_message.message)?;
+
+            /* Verify if message ids are not exhausted */
+            let next_id: u64 = self.next_id.checked_add(1).ok_or(CrudError::Overflow)?;
+
+            /* Verify if message indexes are not exhausted */
+            let messages_len: u32 = self.messages_len.checked_add(1).ok_or(CrudError::Overflow)?;
+
+            // insert message
+            new_message.id = self.next_id;
+            self.next_id = next_id;
+
+            let message: String = new_message.message.clone();
+            let created_at: Timestamp = new_message.created_at;
+            let index: u32 = self.messages_len;
+            self.messages.insert(index, &new_message);
+            self.messages_len = messages_len;
+            self.increment_sender_count(caller);
+            self.create_counts.insert(caller, &self.create_count(caller).saturating_add(1));
+
+            if import {
+                // An imported message only becomes the active one if it is not older than the current one
+                let is_latest: bool = self.get_caller_message(caller).is_none_or(|m: Message| created_at >= m.created_at);
+                if is_latest {
+                    self.set_active_message(caller, Some(index));
+                }
+            } else {
+                self.set_active_message(caller, Some(index));
+                self.last_creates.insert(caller, &created_at);
+                self.last_activity = created_at;
+            }
+
+            if charge_fee {
+                self.accrued_fees = self.accrued_fees.saturating_add(self.env().transferred_value());
+            }
+
+            self.env().emit_event(MessageCreated { sender: caller, message, created_at });
+
+            Ok(index)
+        }
+
+        /* Private function to update the message at index
+        *  Check if sender is not blocked
+        *  Check if message has not expired
+        *  Check if message is not locked
+        *  Check if message is still within the edit window
+        *  Trim surrounding whitespace if trim_on_compare is enabled
+        *  Check if message is not blank
+        *  Check if message has the minimal length
+        *  Check if message does not exceed the maximal length
+        *  Check if message does not contain a banned word
+        *  Check if last message is identical
+        */
+        fn update_message_by_index(&mut self, caller: AccountId, index: u32, message: String) -> Result<(), CrudError> {
+
+            /* Verify if sender is not blocked */
+            self.is_sender_blocked(caller)?;
+
+            let mut updated: Message = self.messages.get(index).unwrap();
+            let updated_at: Timestamp = Self::env().block_timestamp();
+
+            /* Verify if message has not expired, an expired message is never readable again */
+            if updated.is_expired(updated_at) {
+                return Err(CrudError::AnyMessageFound);
+            }
+
+            /* Verify if message is not locked */
+            if updated.locked {
+                return Err(CrudError::MessageLocked);
+            }
+
+            /* Verify if message is still within the edit window */
+            if self.edit_window > 0 && updated_at.saturating_sub(updated.created_at) > self.edit_window {
+                return Err(CrudError::EditWindowExpired);
+            }
+
+            /* Trim surrounding whitespace if enabled, the trimmed version is stored */
+            let message: String = if self.trim_on_compare { String::from(message.trim()) } else { message };
+
+            /* Verify if message is not blank */
+            self.is_message_blank(&message)?;
+
+            /* Verify if message length is between minimal and maximal length */
+            self.is_message_length_valid(&message)?;
+
+            /* Verify if message does not contain a banned word */
+            self.contains_banned_word(&message)?;
+
+            /* Verify if last message is identical */
+            let old_message: String = updated.message.clone();
+            let is_identical: bool = if self.trim_on_compare { old_message.trim() == message } else { old_message == message };
+            if is_identical {
+                return Err(CrudError::MessageIsIdentical);
+            }
+
+            // Update message using struct method
+            updated.update(message.clone(), updated_at);
+            self.messages.insert(index, &updated);
+            self.last_activity = updated_at;
+
+            self.env().emit_event(MessageUpdated { sender: caller, old_message, new_message: message, updated_at });
+
+            Ok(())
+        }
+
+        // Private function to soft-delete the message at index and refresh the sender active message
+        fn delete_message_by_index(&mut self, caller: AccountId, index: u32, deleted_by: AccountId) {
+            let deleted_at: Timestamp = Self::env().block_timestamp();
+            let mut deleted: Message = self.messages.get(index).unwrap();
+            deleted.delete(deleted_at, deleted_by);
+            self.messages.insert(index, &deleted);
+            self.refresh_active_message(caller);
+            self.last_activity = deleted_at;
+
+            self.env().emit_event(MessageDeleted { sender: caller, deleted_at });
+        }
+
+        // Private function to point the active_messages index to the latest active message of sender, by created_at then id
+        fn refresh_active_message(&mut self, sender: AccountId) {
+            let latest: Option<u32> = self.indexed_messages()
+                .filter(|(_, m): &(u32, Message)| m.sender == sender && m.is_active())
+                .max_by_key(|(_, m): &(u32, Message)| (m.created_at, m.id))
+                .map(|(index, _): (u32, Message)| index);
+
+            self.set_active_message(sender, latest);
+        }
+
+        /* Private function to set the active message of sender
+        * Comments are keyed by sender, they are removed when the active message changes
+        * so they never show up on another message, the comment count of the previous message is reset
+        */
+        fn set_active_message(&mut self, sender: AccountId, index: Option<u32>) {
+            let previous: Option<u32> = self.active_messages.get(sender);
+
+            if previous != index {
+                self.comments.remove(sender);
+
+                if let Some(previous) = previous {
+                    if let Some(mut message) = self.messages.get(previous).filter(|m: &Message| m.comment_count > 0) {
+                        message.comment_count = 0;
+                        self.messages.insert(previous, &message);
+                    }
+                }
+            }
+
+            if let Some(index) = index {
+                self.active_messages.insert(sender, &index);
+            } else {
+                self.active_messages.remove(sender);
+            }
+        }
+
+        /* Private function to permanently remove every message of sender
+        * Genesis message is kept and deleted by deleted_by if it belongs to sender
+        * Comments, likes and votes of sender on other messages are removed too
+        * Return the number of removed messages
+        */
+        fn erase_sender(&mut self, sender: AccountId, deleted_by: AccountId) -> u32 {
+            if self.messages.get(0).is_some_and(|genesis: Message| genesis.sender == sender && genesis.is_active()) {
+                self.delete_message_by_index(sender, 0, deleted_by);
+            }
+
+            self.erase_interactions(sender);
+
+            self.retain_messages(|index: u32, m: &Message| index == 0 || m.sender != sender)
+        }
+
+        // Private function to remove the comments, likes and votes of sender on every message
+        fn erase_interactions(&mut self, sender: AccountId) {
+            let touched: Vec<(u32, Message)> = self.indexed_messages()
+                .filter(|(_, m): &(u32, Message)| m.likers.contains(&sender) || m.has_voted(sender))
+                .collect();
+
+            for (index, mut m) in touched {
+                m.unlike(sender);
+                m.unvote(sender);
+                self.messages.insert(index, &m);
+            }
+
+            for target in self.senders.clone() {
+                let mut comments: Vec<Comment> = match self.comments.get(target) {
+                    Some(comments) => comments,
+                    None => continue,
+                };
+                let before: usize = comments.len();
+                comments.retain(|(commenter, _, _): &Comment| *commenter != sender);
+                let removed: u32 = (before - comments.len()) as u32;
+
+                if removed == 0 {
+                    continue;
+                }
+                self.comments.insert(target, &comments);
+
+                // Comments are on the active message of target, see set_active_message
+                if let Some(index) = self.active_messages.get(target) {
+                    let mut commented: Message = self.messages.get(index).unwrap();
+                    commented.comment_count = commented.comment_count.saturating_sub(removed);
+                    self.messages.insert(index, &commented);
+                }
+            }
+        }
+
+        /* Private function to permanently remove messages not matching keep
+        * Pinned message and reactions follow their message to its new index
+        * Return the number of removed messages
+        */
+        fn retain_messages<F: Fn(u32, &Message) -> bool>(&mut self, keep: F) -> u32 {
+            let before: u32 = self.messages_len;
+            let pinned: Option<u32> = self.pinned;
+            self.pinned = None;
+            let mut kept: u32 = 0;
+
+            for index in 0..before {
+                let m: Message = self.messages.get(index).unwrap();
+                self.messages.remove(index);
+
+                let keys: Vec<String> = self.reaction_keys.get(index).unwrap_or_default();
+                let mut counts: Vec<u32> = Vec::<u32>::new();
+                for key in keys.iter() {
+                    counts.push(self.reactions.get((index, key.clone())).unwrap_or(0));
+                    self.reactions.remove((index, key.clone()));
+                }
+                self.reaction_keys.remove(index);
+
+                if keep(index, &m) {
+                    let new_index: u32 = kept;
+
+                    if pinned == Some(index) {
+                        self.pinned = Some(new_index);
+                    }
+
+                    if !keys.is_empty() {
+                        for (key, count) in keys.iter().zip(counts.iter()) {
+                            self.reactions.insert((new_index, key.clone()), count);
+                        }
+                        self.reaction_keys.insert(new_index, &keys);
+                    }
+
+                    // The same message stays active at its new index, its comments are kept
+                    if self.active_messages.get(m.sender) == Some(index) {
+                        self.active_messages.insert(m.sender, &new_index);
+                    }
+
+                    self.messages.insert(new_index, &m);
+                    kept += 1;
+                } else {
+                    if self.active_messages.get(m.sender) == Some(index) {
+                        self.set_active_message(m.sender, None);
+                    }
+                    self.decrement_sender_count(m.sender);
+                }
+            }
+            self.messages_len = kept;
+
+            // Indexes have shifted, rebuild the active messages index
+            self.reindex_active_messages();
+
+            before - kept
+        }
+
+        /* Private function to rebuild the active_messages index after messages have been removed
+        * Each sender gets its latest active message by created_at then id, as refresh_active_message does
+        */
+        fn reindex_active_messages(&mut self) {
+            let active: Vec<(u32, AccountId, Timestamp, u64)> = self.indexed_messages()
+                .filter(|(_, m): &(u32, Message)| m.is_active())
+                .map(|(index, m): (u32, Message)| (index, m.sender, m.created_at, m.id))
+                .collect();
+
+            let mut senders: Vec<AccountId> = active.iter().map(|(_, sender, _, _)| *sender).collect();
+            senders.sort();
+            senders.dedup();
+
+            for sender in senders {
+                let latest: Option<u32> = active.iter()
+                    .filter(|(_, s, _, _)| *s == sender)
+                    .max_by_key(|(_, _, created_at, id)| (*created_at, *id))
+                    .map(|(index, _, _, _)| *index);
+
+                self.set_active_message(sender, latest);
+            }
+        }
+
+        // Private function to count a new message of sender, adding it to the senders index
+        fn increment_sender_count(&mut self, sender: AccountId) {
+            let count: u32 = self.sender_counts.get(sender).unwrap_or(0);
+            if count == 0 {
+                self.senders.push(sender);
+            }
+            self.sender_counts.insert(sender, &count.saturating_add(1));
+        }
+
+        // Private function to uncount a message of sender, removing it from the senders index on its last one
+        fn decrement_sender_count(&mut self, sender: AccountId) {
+            let count: u32 = self.sender_counts.get(sender).unwrap_or(0).saturating_sub(1);
+            if count == 0 {
+                self.sender_counts.remove(sender);
+                self.senders.retain(|s: &AccountId| *s != sender);
+            } else {
+                self.sender_counts.insert(sender, &count);
+            }
+        }
+
+        // Private function to iterate over messages with their index, in storage order
+        fn indexed_messages(&self) -> impl Iterator<Item = (u32, Message)> + '_ {
+            (0..self.messages_len).filter_map(move |index: u32| self.messages.get(index).map(|m: Message| (index, m)))
+        }
+
+        // Private function to iterate over messages, in storage order
+        fn stored_messages(&self) -> impl Iterator<Item = Message> + '_ {
+            self.indexed_messages().map(|(_, m): (u32, Message)| m)
+        }
+
+        /* Private function to get caller latest message index
+        * Deleted messages included
+        */
+        fn get_caller_latest_index(&self, caller: AccountId) -> Option<u32> {
+            (0..self.messages_len)
+                .rev()
+                .find(|index: &u32| self.messages.get(*index).is_some_and(|m: Message| m.sender == caller))
+        }
+
+        /* Private function to get caller most recent message by created_at
+        * Deleted messages included, a private one is only returned to its sender and the creator
+        */
+        fn get_caller_latest_message(&self, caller: AccountId) -> Option<Message> {
+            self.stored_messages()
+                .filter(|m: &Message| m.sender == caller)
+                .max_by_key(|m: &Message| (m.created_at, m.id))
+                .filter(|m: &Message| self.is_visible(m))
+        }
+
+        /* Private function to get caller message for reads
+        * Latest, not deleted message, hidden once its ttl has expired
+        * Private messages are only returned to their sender and the creator
+        */
+        fn get_readable_message(&self, caller: AccountId) -> Option<Message> {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.get_caller_message(caller).filter(|m: &Message| self.is_visible(m) && !m.is_expired(now))
+        }
+
+        // Private function to check if a message is visible to the env caller, private messages are shown to their sender and the creator only
+        fn is_visible(&self, message: &Message) -> bool {
+            let caller: AccountId = self.env().caller();
+
+            !message.private || caller == message.sender || caller == self.creator
+        }
+
+        // Private function to check if sender latest active message has not expired, private messages included
+        fn is_sender_readable(&self, sender: AccountId) -> bool {
+            let now: Timestamp = self.env().block_timestamp();
+
+            self.get_caller_message(sender).is_some_and(|m: Message| !m.is_expired(now))
+        }
+
+        /* Private function to get caller message 
+        * Latest, not deleted message, looked up through the active_messages index
+        */
+        fn get_caller_message(&self, caller: AccountId) -> Option<Message> {
+            let index: u32 = self.active_messages.get(caller)?;
+            self.messages.get(index)
+        }
+
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        type Event = <CrudContract as ::ink::reflect::ContractEventBase>::Type;
+
+        fn accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn recorded_events() -> Vec<Event> {
+            ink::env::test::recorded_events()
+                .map(|e| <Event as Decode>::decode(&mut &e.data[..]).expect("invalid event"))
+                .collect()
+        }
+
+        #[ink::test]
+        fn create_message_emits_message_created() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(42);
+            assert_eq!(contract.create_message(String::from("Hello from Bob")), Ok(()));
+
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            match &events[0] {
+                Event::MessageCreated(e) => {
+                    assert_eq!(e.sender, accounts.bob);
+                    assert_eq!(e.message, String::from("Hello from Bob"));
+                    assert_eq!(e.created_at, 42);
+                }
+                _ => panic!("expected MessageCreated"),
+            }
+        }
+
+        #[ink::test]
+        fn failed_create_emits_nothing() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("short")), Err(CrudError::MessageTooShort));
+
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.create_message(String::from("Second message of Alice")),
+                Err(CrudError::MessageAlreadyCreatedBySender)
+            );
+
+            assert!(recorded_events().is_empty());
+        }
+
+        #[ink::test]
+        fn update_message_emits_old_and_new_content() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First version")).unwrap();
+            set_timestamp(7);
+            assert_eq!(contract.update_message(String::from("Second version")), Ok(()));
+
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            match &events[1] {
+                Event::MessageUpdated(e) => {
+                    assert_eq!(e.sender, accounts.bob);
+                    assert_eq!(e.old_message, String::from("First version"));
+                    assert_eq!(e.new_message, String::from("Second version"));
+                    assert_eq!(e.updated_at, 7);
+                }
+                _ => panic!("expected MessageUpdated"),
+            }
+        }
+
+        #[ink::test]
+        fn failed_update_emits_nothing() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First version")).unwrap();
+            assert_eq!(contract.update_message(String::from("First version")), Err(CrudError::MessageIsIdentical));
+            assert_eq!(contract.update_message(String::from("short")), Err(CrudError::MessageTooShort));
+
+            assert_eq!(recorded_events().len(), 1);
+        }
+
+        #[ink::test]
+        fn delete_message_emits_block_timestamp() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Message to delete")).unwrap();
+            set_timestamp(1_000);
+            assert_eq!(contract.delete_message(), Ok(()));
+
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            match &events[1] {
+                Event::MessageDeleted(e) => {
+                    assert_eq!(e.sender, accounts.bob);
+                    assert_eq!(e.deleted_at, 1_000);
+                }
+                _ => panic!("expected MessageDeleted"),
+            }
+        }
+
+        #[ink::test]
+        fn delete_without_message_emits_nothing() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.delete_message(), Err(CrudError::AnyMessageFound));
+
+            assert!(recorded_events().is_empty());
+        }
+
+        #[ink::test]
+        fn create_read_update_delete_lifecycle() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("Hello from Bob")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello from Bob")));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Hello from Bob")));
+
+            assert_eq!(contract.update_message(String::from("Bob says hello again")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Bob says hello again")));
+
+            assert_eq!(contract.delete_message(), Ok(()));
+            assert_eq!(contract.read_my_message(), Err(CrudError::MessageWasDeleted));
+            assert_eq!(contract.update_message(String::from("Bob after delete")), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.delete_message(), Err(CrudError::AnyMessageFound));
+
+            assert_eq!(contract.create_message(String::from("Bob comes back")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Bob comes back")));
+            assert_eq!(contract.total_message_count(), 3);
+        }
+
+        #[ink::test]
+        fn read_all_messages_iterates_every_stored_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(1);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            set_caller(accounts.charlie);
+            set_timestamp(2);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            set_caller(accounts.alice);
+            let senders: Vec<AccountId> = contract.read_all_messages().unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.charlie, accounts.bob, accounts.alice]);
+        }
+
+        #[ink::test]
+        fn senders_index_follows_creates_transfers_and_purges() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice, accounts.bob]);
+
+            contract.transfer_message(accounts.charlie).unwrap();
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice, accounts.charlie]);
+
+            set_caller(accounts.charlie);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_senders(), vec![accounts.alice]);
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice, accounts.charlie]);
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.purge_deleted(), Ok(1));
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice]);
+            assert_eq!(contract.total_message_count(), 1);
+            assert_eq!(contract.read_by_index(1), Err(CrudError::IndexOutOfBounds));
+        }
+
+        #[ink::test]
+        fn custom_genesis_is_stored_at_index_zero() {
+            let accounts = accounts();
+            let contract = CrudContract::new_with_genesis(String::from("A custom genesis message"));
+
+            assert_eq!(contract.genesis_message().message, String::from("A custom genesis message"));
+            assert_eq!(contract.read_message_from(accounts.alice), Ok(String::from("A custom genesis message")));
+        }
+
+        #[ink::test]
+        fn read_messages_paged_pages_and_caps() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            for i in 1..=104u64 {
+                set_timestamp(i);
+                contract.create_message(format!("Bob message number {}", i)).unwrap();
+            }
+
+            set_caller(accounts.alice);
+            let first: Vec<Message> = contract.read_messages_paged(0, 3).unwrap();
+            assert_eq!(first.len(), 3);
+            assert_eq!(first[0].created_at, 104);
+            assert_eq!(first[2].created_at, 102);
+
+            assert_eq!(contract.read_messages_paged(0, 500).unwrap().len(), MAX_PAGE_SIZE as usize);
+            assert_eq!(contract.read_messages_paged(100, 10).unwrap().len(), 5);
+            assert_eq!(contract.read_messages_paged(105, 10), Ok(Vec::new()));
+            assert_eq!(contract.read_messages_paged(1_000, 10), Ok(Vec::new()));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_messages_paged(0, 10), Err(CrudError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn single_mode_rejects_second_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(false);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            assert_eq!(
+                contract.create_message(String::from("Second Bob message")),
+                Err(CrudError::MessageAlreadyCreatedBySender)
+            );
+        }
+
+        #[ink::test]
+        fn multi_mode_updates_and_deletes_by_index() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            let first: u32 = contract.create_message_indexed(String::from("First Bob message")).unwrap();
+            let second: u32 = contract.create_message_indexed(String::from("Second Bob message")).unwrap();
+            assert_eq!((first, second), (1, 2));
+            assert_eq!(contract.message_count(), 3);
+
+            assert_eq!(contract.update_message_at(first, String::from("First Bob message, edited")), Ok(()));
+            assert_eq!(contract.read_by_index(first).unwrap().message, String::from("First Bob message, edited"));
+            assert_eq!(contract.read_by_index(second).unwrap().message, String::from("Second Bob message"));
+
+            assert_eq!(contract.delete_message_at(second), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("First Bob message, edited")));
+            assert_eq!(contract.delete_message_at(second), Err(CrudError::AnyMessageFound));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.update_message_at(first, String::from("Charlie edits Bob")), Err(CrudError::Unauthorized));
+            assert_eq!(contract.delete_message_at(first), Err(CrudError::Unauthorized));
+            assert_eq!(contract.delete_message_at(42), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn restore_message_after_delete() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Message to restore")).unwrap();
+            contract.delete_message().unwrap();
+            assert_eq!(contract.restore_message(), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Message to restore")));
+            assert_eq!(contract.read_full_message_from(accounts.bob).unwrap().deleted_at, None);
+        }
+
+        #[ink::test]
+        fn restore_message_errors() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.restore_message(), Err(CrudError::AnyMessageFound));
+
+            contract.create_message(String::from("Active Bob message")).unwrap();
+            assert_eq!(contract.restore_message(), Err(CrudError::MessageNotDeleted));
+        }
+
+        #[ink::test]
+        fn restore_message_keeps_one_active_message_in_single_mode() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Charlie message to give")).unwrap();
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Bob message to delete")).unwrap();
+            contract.delete_message().unwrap();
+
+            set_caller(accounts.charlie);
+            contract.transfer_message(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.restore_message(), Err(CrudError::MessageAlreadyCreatedBySender));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Charlie message to give")));
+            assert_eq!(contract.message_count(), 2);
+        }
+
+        #[ink::test]
+        fn message_counts_follow_creates_and_deletes() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            assert_eq!((contract.message_count(), contract.total_message_count()), (1, 1));
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!((contract.message_count(), contract.total_message_count()), (3, 3));
+
+            contract.delete_message().unwrap();
+            assert_eq!((contract.message_count(), contract.total_message_count()), (2, 3));
+        }
+
+        #[ink::test]
+        fn max_length_boundary_on_create_and_update() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            let at_limit: String = "a".repeat(DEFAULT_MAX_LENGTH as usize);
+            let over_limit: String = "b".repeat(DEFAULT_MAX_LENGTH as usize + 1);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(over_limit.clone()), Err(CrudError::MessageTooLong));
+            assert_eq!(contract.create_message(at_limit), Ok(()));
+            assert_eq!(contract.update_message(over_limit), Err(CrudError::MessageTooLong));
+        }
+
+        #[ink::test]
+        fn max_length_is_set_by_constructor() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_length(20);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message("c".repeat(21)), Err(CrudError::MessageTooLong));
+            assert_eq!(contract.create_message("c".repeat(20)), Ok(()));
+        }
+
+        #[ink::test]
+        fn transfer_ownership_moves_admin_access() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            assert_eq!(contract.transfer_ownership(accounts.bob), Ok(()));
+            assert_eq!(contract.owner(), accounts.bob);
+            assert_eq!(contract.read_all_messages().err(), Some(CrudError::Unauthorized));
+
+            set_caller(accounts.bob);
+            assert!(contract.read_all_messages().is_ok());
+        }
+
+        #[ink::test]
+        fn transfer_ownership_rejects_zero_address_and_non_owner() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            assert_eq!(contract.transfer_ownership(AccountId::from([0u8; 32])), Err(CrudError::ZeroAddress));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.transfer_ownership(accounts.bob), Err(CrudError::Unauthorized));
+            assert_eq!(contract.owner(), accounts.alice);
+        }
+
+        #[ink::test]
+        fn message_timestamps_for_created_edited_and_deleted() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(10);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.get_message_timestamps(accounts.bob), Ok((10, 10, None)));
+
+            set_timestamp(20);
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.get_message_timestamps(accounts.bob), Ok((10, 20, None)));
+
+            set_timestamp(30);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_message_timestamps(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_latest_message_timestamps(accounts.bob), Ok((10, 20, Some(30))));
+            assert_eq!(contract.get_latest_message_timestamps(accounts.charlie), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn edit_count_counts_successful_edits_only() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(0));
+
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(1));
+
+            assert_eq!(contract.update_message(String::from("Hello again from Bob")), Err(CrudError::MessageIsIdentical));
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(1));
+        }
+
+        #[ink::test]
+        fn paused_contract_rejects_writes_and_serves_reads() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.set_paused(true), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.set_paused(true), Ok(()));
+            assert!(contract.is_paused());
+            assert!(contract.read_all_messages().is_ok());
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.update_message(String::from("Paused update")), Err(CrudError::ContractPaused));
+            assert_eq!(contract.delete_message(), Err(CrudError::ContractPaused));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.create_message(String::from("Paused create")), Err(CrudError::ContractPaused));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Hello from Bob")));
+            assert_eq!(contract.get_senders(), vec![accounts.alice, accounts.bob]);
+
+            set_caller(accounts.alice);
+            contract.set_paused(false).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contract.create_message(String::from("Unpaused create")), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(contract.update_message(String::from("Unpaused update")), Ok(()));
+            assert_eq!(contract.delete_message(), Ok(()));
+        }
+
+        #[ink::test]
+        fn like_message_counts_once_per_liker() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.like_message(accounts.bob), Ok(()));
+            assert_eq!(contract.get_likes(accounts.bob), Ok(1));
+            assert_eq!(contract.like_message(accounts.bob), Err(CrudError::AlreadyLiked));
+            assert_eq!(contract.get_likes(accounts.bob), Ok(1));
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            set_caller(accounts.django);
+            assert_eq!(contract.like_message(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_likes(accounts.bob), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn blocked_sender_cannot_write_but_is_readable() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.block_sender(accounts.charlie), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.block_sender(accounts.bob), Ok(()));
+            assert_eq!(contract.block_sender(accounts.charlie), Ok(()));
+            assert!(contract.is_blocked(accounts.bob));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.update_message(String::from("Blocked update")), Err(CrudError::SenderBlocked));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Hello from Bob")));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.create_message(String::from("Blocked create")), Err(CrudError::SenderBlocked));
+
+            set_caller(accounts.alice);
+            contract.unblock_sender(accounts.charlie).unwrap();
+            assert!(!contract.is_blocked(accounts.charlie));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.create_message(String::from("Unblocked create")), Ok(()));
+        }
+
+        #[ink::test]
+        fn search_messages_hits_misses_and_multiple_matches() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Bob likes Rust a lot")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Charlie likes ink too")).unwrap();
+
+            let hit: Vec<Message> = contract.search_messages(String::from("Rust"));
+            assert_eq!(hit.len(), 1);
+            assert_eq!(hit[0].sender, accounts.bob);
+
+            assert_eq!(contract.search_messages(String::from("rust")), Vec::new());
+            assert_eq!(contract.search_messages(String::from("likes")).len(), 2);
+        }
+
+        #[ink::test]
+        fn purge_deleted_shrinks_storage_and_keeps_active_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.purge_deleted(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.total_message_count(), 3);
+            assert_eq!(contract.purge_deleted(), Ok(1));
+            assert_eq!(contract.total_message_count(), 2);
+            assert_eq!(contract.read_message_from(accounts.charlie), Ok(String::from("Hello from Charlie")));
+            assert_eq!(contract.get_senders(), vec![accounts.alice, accounts.charlie]);
+            assert_eq!(contract.purge_deleted(), Ok(0));
+        }
+
+        #[ink::test]
+        fn replies_are_recorded_and_listed() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.create_reply(String::from("Replying to nobody"), accounts.charlie),
+                Err(CrudError::AnyMessageFound)
+            );
+            assert_eq!(contract.create_reply(String::from("Welcome Alice, from Bob"), accounts.alice), Ok(()));
+            assert_eq!(contract.read_full_message_from(accounts.bob).unwrap().reply_to, Some(accounts.alice));
+
+            let replies: Vec<Message> = contract.get_replies_to(accounts.alice);
+            assert_eq!(replies.len(), 1);
+            assert_eq!(replies[0].sender, accounts.bob);
+            assert_eq!(contract.get_replies_to(accounts.bob), Vec::new());
+        }
+
+        #[ink::test]
+        fn batch_seed_skips_invalid_entries() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            let entries: Vec<(AccountId, String)> = vec![
+                (accounts.bob, String::from("Seeded Bob message")),
+                (accounts.charlie, String::from("short")),
+                (accounts.bob, String::from("Second Bob message")),
+                (accounts.django, String::from("Seeded Django message")),
+            ];
+            assert_eq!(contract.batch_seed(entries.clone()), Ok(2));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Seeded Bob message")));
+            assert_eq!(contract.read_message_from(accounts.charlie), Err(CrudError::AnyMessageFound));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.batch_seed(entries), Err(CrudError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn read_messages_between_is_inclusive() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(10);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            set_timestamp(20);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            set_caller(accounts.alice);
+            let both: Vec<AccountId> = contract.read_messages_between(10, 20).unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(both, vec![accounts.charlie, accounts.bob]);
+            assert_eq!(contract.read_messages_between(11, 20).unwrap().len(), 1);
+            assert_eq!(contract.read_messages_between(21, 30), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.read_messages_between(20, 10), Ok(Vec::new()));
+        }
+
+        #[ink::test]
+        fn read_public_messages_lists_opted_in_senders() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Public Bob message")).unwrap();
+            contract.set_public(true);
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Quiet Charlie message")).unwrap();
+
+            let public: Vec<Message> = contract.read_public_messages();
+            assert_eq!(public.len(), 1);
+            assert_eq!(public[0].sender, accounts.bob);
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.read_public_messages(), Vec::new());
+        }
+
+        #[ink::test]
+        fn length_errors_are_distinct() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message("a".repeat(9)), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.create_message("a".repeat(281)), Err(CrudError::MessageTooLong));
+        }
+
+        #[ink::test]
+        fn genesis_message_is_the_constructor_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            let genesis: Message = contract.genesis_message();
+            assert_eq!(genesis.message, String::from("I created my ULTIMATE CRUD contract for Ronin Club"));
+            assert_eq!(genesis.sender, accounts.alice);
+
+            set_timestamp(5);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.genesis_message().deleted_at, Some(5));
+        }
+
+        #[ink::test]
+        fn history_grows_once_per_successful_edit() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(1);
+            contract.create_message(String::from("Version one of Bob")).unwrap();
+            assert_eq!(contract.get_message_history(accounts.bob), Ok(Vec::new()));
+
+            set_timestamp(2);
+            contract.update_message(String::from("Version two of Bob")).unwrap();
+            assert_eq!(contract.update_message(String::from("Version two of Bob")), Err(CrudError::MessageIsIdentical));
+            assert_eq!(
+                contract.get_message_history(accounts.bob),
+                Ok(vec![(String::from("Version one of Bob"), 1)])
+            );
+            assert_eq!(contract.read_my_message(), Ok(String::from("Version two of Bob")));
+        }
+
+        #[ink::test]
+        fn reads_work_on_an_immutable_contract() {
+            let accounts = accounts();
+            let contract = CrudContract::new();
+
+            assert_eq!(
+                contract.read_message_from(accounts.alice),
+                Ok(String::from("I created my ULTIMATE CRUD contract for Ronin Club"))
+            );
+            assert_eq!(contract.read_all_messages().unwrap().len(), 1);
+        }
+
+        #[ink::test]
+        fn sender_quota_counts_deleted_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_per_sender(2);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.delete_message().unwrap();
+            assert_eq!(contract.create_message(String::from("Second Bob message")), Ok(()));
+            contract.delete_message().unwrap();
+            assert_eq!(
+                contract.create_message(String::from("Third Bob message")),
+                Err(CrudError::SenderQuotaExceeded)
+            );
+        }
+
+        #[ink::test]
+        fn zero_sender_quota_is_unlimited() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_per_sender(0);
+
+            set_caller(accounts.bob);
+            for _ in 0..3 {
+                contract.create_message(String::from("Another Bob message")).unwrap();
+                contract.delete_message().unwrap();
+            }
+            assert_eq!(contract.create_message(String::from("Another Bob message")), Ok(()));
+        }
+
+        #[ink::test]
+        fn create_fee_is_required_on_every_create_path() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_fee(10);
+
+            set_caller(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(9);
+            assert_eq!(contract.create_message(String::from("Underpaid Bob message")), Err(CrudError::InsufficientFee));
+            assert_eq!(
+                contract.create_reply(String::from("Underpaid Bob reply"), accounts.alice),
+                Err(CrudError::InsufficientFee)
+            );
+            assert_eq!(
+                contract.create_tagged_message(String::from("Underpaid Bob tags"), Vec::new()),
+                Err(CrudError::InsufficientFee)
+            );
+            assert_eq!(
+                contract.create_message_with_ttl(String::from("Underpaid Bob ttl"), 5),
+                Err(CrudError::InsufficientFee)
+            );
+            assert_eq!(
+                contract.create_message_with_hash(String::from("Underpaid Bob hash"), [0; 32]),
+                Err(CrudError::InsufficientFee)
+            );
+            assert_eq!(
+                contract.create_private_message(String::from("Underpaid Bob secret")),
+                Err(CrudError::InsufficientFee)
+            );
+            assert_eq!(contract.accrued_fees(), 0);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.create_reply(String::from("Paid Bob reply"), accounts.alice), Ok(()));
+            assert_eq!(contract.accrued_fees(), 10);
+        }
+
+        #[ink::test]
+        fn only_creator_can_withdraw_fees() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_fee(10);
+
+            set_caller(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            contract.create_message(String::from("Paid Bob message")).unwrap();
+            assert_eq!(contract.withdraw(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(ink::env::test::callee::<ink::env::DefaultEnvironment>(), 10);
+            assert_eq!(contract.withdraw(), Ok(()));
+            assert_eq!(contract.accrued_fees(), 0);
+        }
+
+        #[ink::test]
+        fn tags_are_capped_and_filterable() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            let too_many: Vec<String> = (0..6).map(|i| format!("tag{}", i)).collect();
+            assert_eq!(
+                contract.create_tagged_message(String::from("Too many tags here"), too_many),
+                Err(CrudError::TooManyTags)
+            );
+            assert_eq!(
+                contract.create_tagged_message(String::from("Tag is far too long"), vec!["a".repeat(21)]),
+                Err(CrudError::TooManyTags)
+            );
+            let five: Vec<String> = vec![String::from("rust"), String::from("ink"), "a".repeat(20), String::from("d"), String::from("e")];
+            assert_eq!(contract.create_tagged_message(String::from("Bob tags his post"), five), Ok(()));
+
+            set_caller(accounts.charlie);
+            contract.create_tagged_message(String::from("Charlie tags too"), vec![String::from("ink")]).unwrap();
+
+            assert_eq!(contract.get_messages_by_tag(String::from("rust")).len(), 1);
+            assert_eq!(contract.get_messages_by_tag(String::from("ink")).len(), 2);
+            assert_eq!(contract.get_messages_by_tag(String::from("none")), Vec::new());
+
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_messages_by_tag(String::from("ink")).len(), 1);
+        }
+
+        #[ink::test]
+        fn read_my_message_reads_the_caller_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello from Bob")));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.read_my_message(), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello from Charlie")));
+        }
+
+        #[ink::test]
+        fn only_creator_can_terminate() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.terminate(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            let should_terminate = move || contract.terminate().unwrap();
+            ink::env::test::assert_contract_termination::<ink::env::DefaultEnvironment, _>(should_terminate, accounts.alice, 1000000);
+        }
+
+        #[ink::test]
+        fn senders_are_paged_with_optional_deleted() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            for sender in [accounts.bob, accounts.charlie, accounts.django] {
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+            contract.delete_message().unwrap();
+
+            assert_eq!(contract.get_senders_paged(0, 2), vec![accounts.alice, accounts.bob]);
+            assert_eq!(contract.get_senders_paged(2, 2), vec![accounts.charlie]);
+            assert_eq!(contract.get_senders_paged(3, 2), Vec::new());
+            assert_eq!(contract.get_senders_paged_filtered(2, 2, true), vec![accounts.charlie, accounts.django]);
+            assert_eq!(contract.get_all_senders(true).len(), 4);
+            assert_eq!(contract.get_all_senders(false).len(), 3);
+        }
+
+        #[ink::test]
+        fn has_message_and_has_deleted_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert!(contract.has_message(accounts.bob));
+            assert!(!contract.has_deleted_message(accounts.bob));
+
+            contract.delete_message().unwrap();
+            assert!(!contract.has_message(accounts.bob));
+            assert!(contract.has_deleted_message(accounts.bob));
+
+            assert!(!contract.has_message(accounts.charlie));
+            assert!(!contract.has_deleted_message(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn admin_delete_message_is_creator_or_moderator_only() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contract.admin_delete_message(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.admin_delete_message(accounts.bob), Ok(()));
+            assert!(!contract.has_message(accounts.bob));
+            assert_eq!(contract.read_latest_from(accounts.bob).unwrap().deleted_by, Some(accounts.alice));
+            assert_eq!(contract.admin_delete_message(accounts.bob), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn admin_deleted_message_cannot_be_restored_by_sender() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.alice);
+            contract.admin_delete_message(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.restore_message(), Err(CrudError::Unauthorized));
+            assert!(!contract.has_message(accounts.bob));
+        }
+
+        #[ink::test]
+        fn blocked_sender_cannot_restore() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            set_caller(accounts.alice);
+            contract.block_sender(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.restore_message(), Err(CrudError::SenderBlocked));
+        }
+
+        #[ink::test]
+        fn moderator_can_block_but_not_transfer_ownership() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.add_moderator(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.add_moderator(accounts.bob), Ok(()));
+            assert!(contract.is_moderator(accounts.bob));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.block_sender(accounts.charlie), Ok(()));
+            assert!(contract.is_blocked(accounts.charlie));
+            assert_eq!(contract.transfer_ownership(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.remove_moderator(accounts.bob), Ok(()));
+            assert!(!contract.is_moderator(accounts.bob));
+            set_caller(accounts.bob);
+            assert_eq!(contract.block_sender(accounts.django), Err(CrudError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn create_message_indexed_returns_the_new_index() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            let index: u32 = contract.create_message_indexed(String::from("Hello from Bob")).unwrap();
+            assert_eq!(index, contract.total_message_count() - 1);
+            assert_eq!(contract.read_by_index(index).unwrap().sender, accounts.bob);
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.create_message_indexed(String::from("Hello from Charlie")), Ok(2));
+        }
+
+        #[ink::test]
+        fn read_active_messages_is_newest_first_without_deleted() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            for (timestamp, sender) in [(1, accounts.bob), (2, accounts.charlie), (3, accounts.django)] {
+                set_timestamp(timestamp);
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+            set_caller(accounts.charlie);
+            contract.delete_message().unwrap();
+
+            let senders: Vec<AccountId> = contract.read_active_messages().iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.django, accounts.bob, accounts.alice]);
+        }
+
+        #[ink::test]
+        fn pin_unpin_and_deleted_pins() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.pin_message(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.pin_message(accounts.charlie), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.pin_message(accounts.bob), Ok(()));
+            assert_eq!(contract.get_pinned().unwrap().sender, accounts.bob);
+            assert_eq!(contract.unpin(), Ok(()));
+            assert_eq!(contract.get_pinned(), None);
+
+            contract.pin_message(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_pinned(), None);
+            set_caller(accounts.alice);
+            assert_eq!(contract.pin_message(accounts.bob), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn last_activity_follows_successful_mutations() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            assert_eq!(contract.last_activity(), 0);
+
+            set_caller(accounts.bob);
+            set_timestamp(5);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.last_activity(), 5);
+
+            set_timestamp(6);
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.last_activity(), 6);
+
+            set_timestamp(7);
+            assert!(contract.update_message(String::from("short")).is_err());
+            assert!(contract.create_message(String::from("Hello twice from Bob")).is_err());
+            assert_eq!(contract.last_activity(), 6);
+
+            set_timestamp(8);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.last_activity(), 8);
+        }
+
+        #[ink::test]
+        fn min_length_counts_characters_not_bytes() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.validate_message("a".repeat(9)), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.validate_message("a".repeat(10)), Ok(()));
+            assert_eq!(contract.validate_message("é".repeat(9)), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.validate_message("é".repeat(10)), Ok(()));
+            assert_eq!(contract.validate_message("😀".repeat(3)), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.create_message("😀".repeat(10)), Ok(()));
+        }
+
+        #[ink::test]
+        fn clear_all_keeps_only_the_genesis_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            let genesis: Message = contract.genesis_message();
+
+            for sender in [accounts.bob, accounts.charlie] {
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+            assert_eq!(contract.clear_all(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.clear_all(), Ok(()));
+            assert_eq!(contract.read_all_messages(), Ok(vec![genesis]));
+            assert!(!contract.has_message(accounts.bob));
+        }
+
+        #[ink::test]
+        fn read_messages_from_many_pairs_each_sender() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            contract.delete_message().unwrap();
+
+            assert_eq!(
+                contract.read_messages_from_many(vec![accounts.bob, accounts.charlie, accounts.django]),
+                vec![
+                    (accounts.bob, Some(String::from("Hello from Bob"))),
+                    (accounts.charlie, None),
+                    (accounts.django, None),
+                ]
+            );
+            assert_eq!(contract.read_messages_from_many(vec![accounts.bob; 101]).len(), 100);
+        }
+
+        #[ink::test]
+        fn min_length_is_configurable() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_min(3);
+            assert_eq!(contract.min_length(), 3);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("hi")), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.create_message(String::from("hey")), Ok(()));
+        }
+
+        #[ink::test]
+        fn default_min_length_is_ten() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            assert_eq!(contract.min_length(), 10);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("hey")), Err(CrudError::MessageTooShort));
+        }
+
+        #[ink::test]
+        fn ownership_handshake_accept_reject_and_cancel() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.propose_new_owner(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.propose_new_owner(accounts.bob), Ok(()));
+            assert_eq!(contract.pending_owner(), Some(accounts.bob));
+            assert_eq!(contract.cancel_ownership_transfer(), Ok(()));
+            assert_eq!(contract.pending_owner(), None);
+
+            contract.propose_new_owner(accounts.bob).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contract.accept_ownership(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.accept_ownership(), Ok(()));
+            assert_eq!(contract.owner(), accounts.bob);
+            assert_eq!(contract.pending_owner(), None);
+        }
+
+        #[ink::test]
+        fn private_message_is_hidden_from_public_reads() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(1);
+            contract.create_private_message(String::from("A secret from Bob")).unwrap();
+            let id: u64 = contract.read_full_message_from(accounts.bob).unwrap().id;
+            assert_eq!(contract.read_my_message(), Ok(String::from("A secret from Bob")));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.read_message_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.read_full_message_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.read_latest_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_message_by_id(id), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.verify_hash(accounts.bob, [0; 32]), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_message_history(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.read_messages_from_many(vec![accounts.bob]), vec![(accounts.bob, None)]);
+            assert!(contract.read_active_messages().iter().all(|m| m.sender != accounts.bob));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("A secret from Bob")));
+            assert!(contract.read_all_messages().unwrap().iter().any(|m| m.sender == accounts.bob));
+        }
+
+        #[ink::test]
+        fn events_are_filterable_by_sender_topic() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.create_message(String::from("Second Bob message")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            contract.like_message(accounts.bob).unwrap();
+
+            let events: Vec<ink::env::test::EmittedEvent> = ink::env::test::recorded_events().collect();
+            assert_eq!(events.len(), 4);
+
+            /* The sender topic is the same for one sender and differs between senders */
+            assert_eq!(events[0].topics[1], events[1].topics[1]);
+            assert_ne!(events[0].topics[1], events[2].topics[1]);
+
+            /* MessageLiked has the liker and the message owner as topics */
+            assert_eq!(events[3].topics.len(), 3);
+            assert_ne!(events[3].topics[1], events[3].topics[2]);
+            assert!(matches!(
+                <Event as Decode>::decode(&mut &events[3].data[..]).unwrap(),
+                Event::MessageLiked(MessageLiked { liker, sender, likes: 1 }) if liker == accounts.charlie && sender == accounts.bob
+            ));
+        }
+
+        #[ink::test]
+        fn sender_count_matches_get_senders() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+            assert_eq!(contract.get_sender_count(), contract.get_senders().len() as u32);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.create_message(String::from("Second Bob message")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!(contract.get_sender_count(), 3);
+            assert_eq!(contract.get_sender_count(), contract.get_senders().len() as u32);
+
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_sender_count(), 2);
+            assert_eq!(contract.get_sender_count(), contract.get_senders().len() as u32);
+        }
+
+        #[ink::test]
+        fn edit_window_limits_updates() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_edit_window(100);
+
+            set_caller(accounts.bob);
+            set_timestamp(1000);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_timestamp(1100);
+            assert_eq!(contract.update_message(String::from("Edited at the edge")), Ok(()));
+            set_timestamp(1101);
+            assert_eq!(contract.update_message(String::from("Edited too late")), Err(CrudError::EditWindowExpired));
+        }
+
+        #[ink::test]
+        fn zero_edit_window_always_allows_updates() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_edit_window(0);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_timestamp(1_000_000);
+            assert_eq!(contract.update_message(String::from("Edited much later")), Ok(()));
+        }
+
+        #[ink::test]
+        fn votes_count_once_and_score_can_be_negative() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.upvote_message(accounts.charlie), Err(CrudError::AnyMessageFound));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.upvote_message(accounts.bob), Ok(()));
+            assert_eq!(contract.downvote_message(accounts.bob), Err(CrudError::AlreadyVoted));
+            assert_eq!(contract.get_score(accounts.bob), Ok(1));
+
+            for voter in [accounts.django, accounts.eve] {
+                set_caller(voter);
+                contract.downvote_message(accounts.bob).unwrap();
+            }
+            assert_eq!(contract.get_score(accounts.bob), Ok(-1));
+        }
+
+        #[ink::test]
+        fn read_full_message_from_returns_every_field() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(7);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+
+            let mut expected: Message = Message::new(accounts.bob, String::from("Hello from Bob"), 7);
+            expected.id = 1;
+            assert_eq!(contract.read_full_message_from(accounts.bob), Ok(expected));
+            assert_eq!(contract.read_full_message_from(accounts.charlie), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn trim_on_compare_treats_whitespace_as_identical() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_trim(true);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.update_message(String::from("  Hello from Bob ")), Err(CrudError::MessageIsIdentical));
+            assert_eq!(contract.update_message(String::from(" Hello again from Bob ")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello again from Bob")));
+        }
+
+        #[ink::test]
+        fn whitespace_differences_count_without_trim() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_trim(false);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.update_message(String::from("Hello from Bob ")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello from Bob ")));
+        }
+
+        #[ink::test]
+        fn read_deleted_messages_is_newest_deletion_first() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.read_deleted_messages(), Err(CrudError::AnyMessageFound));
+
+            for (timestamp, sender) in [(1, accounts.bob), (2, accounts.charlie), (3, accounts.django)] {
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+                set_timestamp(10 - timestamp);
+                if sender != accounts.django {
+                    contract.delete_message().unwrap();
+                }
+            }
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_deleted_messages(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            let senders: Vec<AccountId> = contract.read_deleted_messages().unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.bob, accounts.charlie]);
+        }
+
+        #[ink::test]
+        fn transfer_message_moves_ownership_or_collides() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.transfer_message(accounts.alice), Err(CrudError::MessageAlreadyCreatedBySender));
+            assert_eq!(contract.transfer_message(accounts.charlie), Ok(()));
+
+            assert!(!contract.has_message(accounts.bob));
+            assert_eq!(contract.read_message_from(accounts.charlie), Ok(String::from("Hello from Bob")));
+        }
+
+        #[ink::test]
+        fn same_block_messages_sort_by_id() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_timestamp(0);
+            for sender in [accounts.bob, accounts.charlie, accounts.django] {
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+
+            set_caller(accounts.alice);
+            let ids: Vec<u64> = contract.read_all_messages_sorted(true).unwrap().iter().map(|m| m.id).collect();
+            assert_eq!(ids, vec![0, 1, 2, 3]);
+            assert_eq!(contract.get_message_by_id(2).unwrap().sender, accounts.charlie);
+            assert_eq!(contract.get_message_by_id(4), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn create_count_persists_across_deletes() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_count(accounts.bob), 0);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.delete_message().unwrap();
+            contract.create_message(String::from("Second Bob message")).unwrap();
+            assert_eq!(contract.create_count(accounts.bob), 2);
+            assert!(contract.create_message(String::from("Third Bob message")).is_err());
+            assert_eq!(contract.create_count(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn cooldown_rate_limits_creates() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_cooldown(100);
+
+            set_caller(accounts.bob);
+            set_timestamp(1000);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.delete_message().unwrap();
+            set_timestamp(1099);
+            assert_eq!(contract.create_message(String::from("Second Bob message")), Err(CrudError::RateLimited));
+            set_timestamp(1100);
+            assert_eq!(contract.create_message(String::from("Second Bob message")), Ok(()));
+        }
+
+        #[ink::test]
+        fn version_matches_the_constant() {
+            let contract = CrudContract::new();
+
+            assert_eq!(contract.version(), CONTRACT_VERSION);
+            assert_eq!(contract.semver(), String::from(env!("CARGO_PKG_VERSION")));
+        }
+
+        #[ink::test]
+        fn upgrade_is_creator_only() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.upgrade(Hash::from([1; 32])), Err(CrudError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn is_active_follows_deleted_at() {
+            let accounts = accounts();
+            let mut message: Message = Message::new(accounts.bob, String::from("Hello from Bob"), 1);
+            assert!(message.is_active());
+
+            message.delete(2, accounts.bob);
+            assert!(!message.is_active());
+
+            message.restore();
+            assert!(message.is_active());
+        }
+
+        #[ink::test]
+        fn stats_count_every_field() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            contract.delete_message().unwrap();
+            contract.create_message(String::from("Charlie is back now")).unwrap();
+            set_caller(accounts.django);
+            contract.create_message(String::from("Hello from Django")).unwrap();
+            contract.delete_message().unwrap();
+
+            assert_eq!(contract.stats(), Stats { total: 5, active: 3, deleted: 2, unique_senders: 3 });
+        }
+
+        #[ink::test]
+        fn delete_all_my_messages_in_multi_mode() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.delete_all_my_messages(), Err(CrudError::AnyMessageFound));
+            for text in ["First Bob message", "Second Bob message", "Third Bob message"] {
+                contract.create_message(String::from(text)).unwrap();
+            }
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.delete_all_my_messages(), Ok(3));
+            assert!(!contract.has_message(accounts.bob));
+            assert!(contract.has_message(accounts.charlie));
+            assert_eq!(contract.delete_all_my_messages(), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn validate_message_matches_create_errors() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.validate_message(String::from("short")), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.validate_message("a".repeat(281)), Err(CrudError::MessageTooLong));
+            assert_eq!(contract.validate_message(String::from("A valid message")), Ok(()));
+            assert!(!contract.has_message(accounts.bob));
+            assert_eq!(contract.create_message(String::from("short")), contract.validate_message(String::from("short")));
+        }
+
+        #[ink::test]
+        fn senders_active_since_straddles_the_threshold() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            for (timestamp, sender) in [(10, accounts.bob), (20, accounts.charlie), (30, accounts.django)] {
+                set_timestamp(timestamp);
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+            contract.delete_message().unwrap();
+
+            assert_eq!(contract.senders_active_since(20), vec![accounts.charlie]);
+            assert_eq!(contract.senders_active_since(10), vec![accounts.bob, accounts.charlie]);
+            assert_eq!(contract.senders_active_since(0).len(), 3);
+        }
+
+        #[ink::test]
+        fn new_with_genesis_uses_the_given_text() {
+            let accounts = accounts();
+            let contract = CrudContract::new_with_genesis(String::from("A custom genesis message"));
+
+            let genesis: Message = contract.genesis_message();
+            assert_eq!(genesis.message, String::from("A custom genesis message"));
+            assert_eq!(genesis.sender, accounts.alice);
+            assert_eq!(contract.read_message_from(accounts.alice), Ok(String::from("A custom genesis message")));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Genesis message is too short")]
+        fn new_with_genesis_rejects_short_text() {
+            CrudContract::new_with_genesis(String::from("short"));
+        }
+
+        #[ink::test]
+        fn deleted_by_distinguishes_self_and_admin_deletes() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.get_deleted_by(accounts.bob), Ok(None));
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_deleted_by(accounts.bob), Ok(Some(accounts.bob)));
+
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            set_caller(accounts.alice);
+            contract.admin_delete_message(accounts.charlie).unwrap();
+            assert_eq!(contract.get_deleted_by(accounts.charlie), Ok(Some(accounts.alice)));
+            assert_eq!(contract.read_latest_from(accounts.charlie).unwrap().deleted_by, Some(accounts.alice));
+
+            assert_eq!(contract.get_deleted_by(accounts.django), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn upsert_creates_then_updates() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.upsert_message(String::from("Hello from Bob")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello from Bob")));
+            assert_eq!(contract.upsert_message(String::from("Hello again from Bob")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Hello again from Bob")));
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(1));
+            assert_eq!(contract.upsert_message(String::from("Hello again from Bob")), Err(CrudError::MessageIsIdentical));
+            assert_eq!(contract.upsert_message(String::from("short")), Err(CrudError::MessageTooShort));
+        }
+
+        #[ink::test]
+        fn contains_sender_for_present_deleted_and_absent() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            contract.delete_message().unwrap();
+
+            assert!(contract.contains_sender(accounts.bob));
+            assert!(!contract.contains_sender(accounts.charlie));
+            assert!(!contract.contains_sender(accounts.django));
+        }
+
+        #[ink::test]
+        fn expired_message_is_hidden_from_every_read() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(100);
+            contract.create_message_with_ttl(String::from("Short lived Bob message"), 50).unwrap();
+            contract.set_public(true);
+            set_caller(accounts.charlie);
+            contract.create_tagged_message(String::from("Charlie tags forever"), vec![String::from("ink")]).unwrap();
+
+            set_timestamp(150);
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Short lived Bob message")));
+            assert!(contract.has_message(accounts.bob));
+            assert_eq!(contract.get_senders(), vec![accounts.alice, accounts.bob, accounts.charlie]);
+            assert_eq!(contract.time_bounds(), Some((0, 100)));
+
+            set_timestamp(151);
+            assert_eq!(contract.read_message_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert!(!contract.has_message(accounts.bob));
+            assert!(!contract.contains_sender(accounts.bob));
+            assert_eq!(contract.get_senders(), vec![accounts.alice, accounts.charlie]);
+            assert_eq!(contract.get_sender_count(), 2);
+            assert_eq!(contract.message_count(), 2);
+            assert!(contract.read_active_messages().iter().all(|m| m.sender != accounts.bob));
+            assert!(contract.hot_messages(151, 10).iter().all(|m| m.sender != accounts.bob));
+            assert_eq!(contract.search_messages(String::from("Short lived")), Vec::new());
+            assert_eq!(contract.read_public_messages(), Vec::new());
+            assert_eq!(contract.get_messages_by_tag(String::from("ink")).len(), 1);
+
+            /* The expired message frees the slot, Bob can create a new message */
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("Bob tries again")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Bob tries again")));
+        }
+
+        #[ink::test]
+        fn is_readable_needs_active_and_unexpired() {
+            let accounts = accounts();
+            let mut message: Message = Message::new(accounts.bob, String::from("Hello from Bob"), 100);
+            assert!(message.is_readable(u64::MAX));
+
+            message.ttl = Some(50);
+            assert!(message.is_readable(150));
+            assert!(!message.is_readable(151));
+
+            message.ttl = None;
+            message.delete(120, accounts.bob);
+            assert!(!message.is_readable(120));
+        }
+
+        #[ink::test]
+        fn export_round_trips_stored_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(3);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_timestamp(4);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.export(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            let exported: Vec<ExportedMessage> = contract.export().unwrap();
+            let expected: Vec<ExportedMessage> = contract.read_all_messages_sorted(true)
+                .unwrap()
+                .into_iter()
+                .map(|m| (m.sender, m.message, m.created_at, m.updated_at, m.deleted_at))
+                .collect();
+            assert_eq!(exported, expected);
+            assert_eq!(exported[1], (accounts.bob, String::from("Hello from Bob"), 3, 3, Some(4)));
+        }
+
+        #[ink::test]
+        fn read_latest_from_includes_deleted_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_latest_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.read_latest_from(accounts.bob).unwrap().deleted_at, None);
+
+            set_timestamp(9);
+            contract.delete_message().unwrap();
+            let latest: Message = contract.read_latest_from(accounts.bob).unwrap();
+            assert_eq!(latest.message, String::from("Hello from Bob"));
+            assert_eq!(latest.deleted_at, Some(9));
+        }
+
+        #[ink::test]
+        fn comments_are_validated_and_counted() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.add_comment(accounts.bob, String::from("Nice message Bob")), Err(CrudError::AnyMessageFound));
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.alice);
+            contract.add_banned_word(String::from("spam")).unwrap();
+
+            set_caller(accounts.charlie);
+            set_timestamp(5);
+            assert_eq!(contract.add_comment(accounts.bob, String::from("          ")), Err(CrudError::MessageBlank));
+            assert_eq!(contract.add_comment(accounts.bob, String::from("Nice")), Err(CrudError::MessageTooShort));
+            assert_eq!(contract.add_comment(accounts.bob, "a".repeat(281)), Err(CrudError::MessageTooLong));
+            assert_eq!(contract.add_comment(accounts.bob, String::from("Buy my spam now")), Err(CrudError::ContainsBannedWord));
+            assert_eq!(contract.add_comment(accounts.bob, String::from("Nice message Bob")), Ok(()));
+
+            assert_eq!(contract.get_comments(accounts.bob), vec![(accounts.charlie, String::from("Nice message Bob"), 5)]);
+            assert_eq!(contract.read_full_message_from(accounts.bob).unwrap().comment_count, 1);
+        }
+
+        #[ink::test]
+        fn comments_do_not_carry_over_to_the_next_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.bob, String::from("Nice message Bob")).unwrap();
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.get_comments(accounts.bob), Vec::new());
+
+            contract.create_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.get_comments(accounts.bob), Vec::new());
+
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.bob, String::from("Nice message again")).unwrap();
+            set_caller(accounts.bob);
+            contract.create_message(String::from("A third Bob message")).unwrap();
+            assert_eq!(contract.get_comments(accounts.bob), Vec::new());
+        }
+
+        #[ink::test]
+        fn blank_messages_are_rejected_on_create_and_update() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("          ")), Err(CrudError::MessageBlank));
+            assert_eq!(contract.create_message(String::from("  Hello from Bob  ")), Ok(()));
+            assert_eq!(contract.update_message(String::from("            ")), Err(CrudError::MessageBlank));
+        }
+
+        #[ink::test]
+        fn banned_words_are_caught_case_insensitively() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.add_banned_word(String::from("spam")), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.add_banned_word(String::from("spam")), Ok(()));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("Buy my SpAm today")), Err(CrudError::ContainsBannedWord));
+            assert_eq!(contract.create_message(String::from("A clean message")), Ok(()));
+            assert_eq!(contract.update_message(String::from("Now with spam inside")), Err(CrudError::ContainsBannedWord));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.remove_banned_word(String::from("spam")), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(contract.update_message(String::from("Now with spam inside")), Ok(()));
+        }
+
+        #[ink::test]
+        fn banned_word_list_is_capped() {
+            let mut contract = CrudContract::new();
+
+            for i in 0..50 {
+                contract.add_banned_word(format!("word{}", i)).unwrap();
+            }
+            assert_eq!(contract.add_banned_word(String::from("onemore")), Err(CrudError::TooManyBannedWords));
+        }
+
+        #[ink::test]
+        fn read_all_messages_sorted_both_ways() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            for (timestamp, sender) in [(1, accounts.bob), (2, accounts.charlie)] {
+                set_timestamp(timestamp);
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+            assert_eq!(contract.read_all_messages_sorted(true), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            let ascending: Vec<AccountId> = contract.read_all_messages_sorted(true).unwrap().iter().map(|m| m.sender).collect();
+            let mut descending: Vec<AccountId> = contract.read_all_messages_sorted(false).unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(ascending, vec![accounts.alice, accounts.bob, accounts.charlie]);
+            descending.reverse();
+            assert_eq!(ascending, descending);
+            assert_eq!(contract.read_all_messages(), contract.read_all_messages_sorted(false));
+        }
+
+        #[ink::test]
+        fn zero_address_targets_are_rejected() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            let zero: AccountId = AccountId::from([0u8; 32]);
+
+            assert_eq!(contract.transfer_ownership(zero), Err(CrudError::ZeroAddress));
+            assert_eq!(contract.block_sender(zero), Err(CrudError::ZeroAddress));
+            assert_eq!(contract.block_sender(accounts.bob), Ok(()));
+
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!(contract.transfer_message(zero), Err(CrudError::ZeroAddress));
+        }
+
+        #[ink::test]
+        fn word_count_ignores_extra_spaces() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.word_count(accounts.bob), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("Supercalifragilistic")).unwrap();
+            assert_eq!(contract.word_count(accounts.bob), Ok(1));
+            contract.update_message(String::from("  Hello   from \t Bob  ")).unwrap();
+            assert_eq!(contract.word_count(accounts.bob), Ok(3));
+        }
+
+        #[ink::test]
+        fn creator_is_the_constructor_caller() {
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            let contract = CrudContract::new();
+
+            assert_eq!(contract.creator(), accounts.bob);
+            assert_eq!(contract.owner(), accounts.bob);
+        }
+
+        #[ink::test]
+        fn locked_message_rejects_updates_but_not_deletes() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.lock_my_message(), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.lock_my_message(), Ok(()));
+            assert_eq!(contract.update_message(String::from("Hello again from Bob")), Err(CrudError::MessageLocked));
+            assert_eq!(contract.unlock_my_message(), Ok(()));
+            assert_eq!(contract.update_message(String::from("Hello again from Bob")), Ok(()));
+
+            contract.lock_my_message().unwrap();
+            assert_eq!(contract.delete_message(), Ok(()));
+        }
+
+        #[ink::test]
+        fn hot_messages_rank_likes_by_age() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_timestamp(100);
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Old but loved Bob")).unwrap();
+            set_timestamp(190);
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("New and liked Charlie")).unwrap();
+
+            for liker in [accounts.django, accounts.eve] {
+                set_caller(liker);
+                contract.like_message(accounts.bob).unwrap();
+            }
+            contract.like_message(accounts.charlie).unwrap();
+
+            /* Bob: 2 likes over 100ms, Charlie: 1 like over 10ms, Alice: no like */
+            let hot: Vec<AccountId> = contract.hot_messages(200, 10).iter().map(|m| m.sender).collect();
+            assert_eq!(hot, vec![accounts.charlie, accounts.bob, accounts.alice]);
+            assert_eq!(contract.hot_messages(200, 1).len(), 1);
+
+            /* Same block messages do not divide by zero */
+            assert_eq!(contract.hot_messages(190, 10)[0].sender, accounts.charlie);
+        }
+
+        #[ink::test]
+        fn deleted_and_absent_senders_get_distinct_errors() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+
+            assert_eq!(contract.read_message_from(accounts.bob), Err(CrudError::MessageWasDeleted));
+            assert_eq!(contract.read_message_from(accounts.charlie), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn like_many_reports_each_target() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+
+            set_caller(accounts.charlie);
+            contract.like_message(accounts.alice).unwrap();
+            assert_eq!(
+                contract.like_many(vec![accounts.bob, accounts.alice, accounts.django]),
+                vec![
+                    (accounts.bob, Ok(())),
+                    (accounts.alice, Err(CrudError::AlreadyLiked)),
+                    (accounts.django, Err(CrudError::AnyMessageFound)),
+                ]
+            );
+            assert_eq!(contract.get_likes(accounts.bob), Ok(1));
+        }
+
+        #[ink::test]
+        fn like_many_reports_targets_past_the_cap() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.charlie);
+            let mut senders: Vec<AccountId> = vec![accounts.django; 100];
+            senders.push(accounts.alice);
+            let results: Vec<(AccountId, Result<(), CrudError>)> = contract.like_many(senders);
+
+            assert_eq!(results.len(), 101);
+            assert_eq!(results[99], (accounts.django, Err(CrudError::AnyMessageFound)));
+            assert_eq!(results[100], (accounts.alice, Err(CrudError::BatchTooLarge)));
+            assert_eq!(contract.get_likes(accounts.alice), Ok(0));
+        }
+
+        #[ink::test]
+        fn message_length_counts_characters() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.message_length(accounts.bob), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.message_length(accounts.bob), Ok(14));
+            contract.update_message("é😀".repeat(5)).unwrap();
+            assert_eq!(contract.message_length(accounts.bob), Ok(10));
+        }
+
+        #[ink::test]
+        fn read_all_messages_filtered_by_deletion() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            assert_eq!(contract.read_all_messages_filtered(true), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.read_all_messages_filtered(true).unwrap().len(), 2);
+            let active: Vec<Message> = contract.read_all_messages_filtered(false).unwrap();
+            assert_eq!(active.len(), 1);
+            assert_eq!(active[0].sender, accounts.alice);
+            assert_eq!(contract.read_all_messages().unwrap().len(), 2);
+        }
+
+        #[ink::test]
+        fn welcome_text_is_set_cleared_and_read() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            assert_eq!(contract.get_welcome(), None);
+
+            assert_eq!(contract.set_welcome(String::from("Welcome to the club")), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(contract.get_welcome(), Some(String::from("Welcome to the club")));
+            assert_eq!(contract.set_welcome(String::new()), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.set_welcome(String::new()), Ok(()));
+            assert_eq!(contract.get_welcome(), None);
+        }
+
+        #[ink::test]
+        fn stale_senders_by_updated_at() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_timestamp(100);
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_timestamp(900);
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            assert_eq!(contract.stale_senders(500, 1000), vec![accounts.alice, accounts.bob]);
+            assert_eq!(contract.stale_senders(2000, 1000), Vec::new());
+
+            set_caller(accounts.bob);
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.stale_senders(500, 1000), vec![accounts.alice]);
+        }
+
+        #[ink::test]
+        fn total_likes_sums_readable_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            assert_eq!(contract.total_likes(), 0);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.like_message(accounts.alice).unwrap();
+            set_caller(accounts.charlie);
+            contract.like_message(accounts.alice).unwrap();
+            contract.like_message(accounts.bob).unwrap();
+            assert_eq!(contract.total_likes(), 3);
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.total_likes(), 2);
+        }
+
+        #[ink::test]
+        fn read_by_index_valid_out_of_range_and_after_purge() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            assert_eq!(contract.read_by_index(1).unwrap().sender, accounts.bob);
+            assert_eq!(contract.read_by_index(2).unwrap().sender, accounts.charlie);
+            assert_eq!(contract.read_by_index(3), Err(CrudError::IndexOutOfBounds));
+
+            /* Purging shifts the following messages down */
+            set_caller(accounts.alice);
+            contract.purge_deleted().unwrap();
+            assert_eq!(contract.read_by_index(1).unwrap().sender, accounts.charlie);
+            assert_eq!(contract.read_by_index(2), Err(CrudError::IndexOutOfBounds));
+        }
+
+        #[ink::test]
+        fn private_messages_are_hidden_by_index() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_private_message(String::from("A secret from Bob")).unwrap();
+            assert_eq!(contract.read_by_index(1).unwrap().sender, accounts.bob);
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.read_by_index(1), Err(CrudError::Unauthorized));
+            assert_eq!(contract.message_owner(1), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.message_owner(1), Ok(accounts.bob));
+        }
+
+        #[ink::test]
+        fn withdraw_pays_accrued_fees_once() {
+            let accounts = accounts();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.frank);
+            let mut contract = CrudContract::new_with_fee(10);
+            let callee: AccountId = accounts.frank;
+            let balance = |who: AccountId| ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(who).unwrap();
+
+            set_caller(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            contract.create_message(String::from("Paid Bob message")).unwrap();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(callee, 10);
+
+            /* The off-chain engine panics instead of failing a transfer, so only the success path is covered */
+            set_caller(accounts.alice);
+            let before: Balance = balance(accounts.alice);
+            assert_eq!(contract.withdraw(), Ok(()));
+            assert_eq!(contract.accrued_fees(), 0);
+            assert_eq!(balance(accounts.alice), before + 10);
+            assert_eq!(balance(callee), 0);
+
+            assert_eq!(contract.withdraw(), Ok(()));
+            assert_eq!(balance(accounts.alice), before + 10);
+        }
+
+        #[ink::test]
+        fn upsert_charges_creates_and_refuses_paid_updates() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_fee(10);
+
+            set_caller(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.upsert_message(String::from("Paid Bob message")), Ok(()));
+            assert_eq!(contract.upsert_message(String::from("Paid Bob update")), Err(CrudError::UnexpectedPayment));
+            assert_eq!(contract.accrued_fees(), 10);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(contract.upsert_message(String::from("Free Bob update")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Free Bob update")));
+        }
+
+        #[ink::test]
+        fn message_owner_matches_creators() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            assert_eq!(contract.message_owner(0), Ok(accounts.alice));
+            assert_eq!(contract.message_owner(1), Ok(accounts.bob));
+            assert_eq!(contract.message_owner(2), Ok(accounts.charlie));
+            assert_eq!(contract.message_owner(3), Err(CrudError::IndexOutOfBounds));
+        }
+
+        #[ink::test]
+        fn reactions_are_counted_per_emoji() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.react(accounts.charlie, String::from("🔥")), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.react(accounts.alice, String::new()), Err(CrudError::InvalidReaction));
+            assert_eq!(contract.react(accounts.alice, "a".repeat(17)), Err(CrudError::InvalidReaction));
+            contract.react(accounts.alice, String::from("🔥")).unwrap();
+            contract.react(accounts.alice, String::from("👍")).unwrap();
+            set_caller(accounts.charlie);
+            contract.react(accounts.alice, String::from("🔥")).unwrap();
+
+            assert_eq!(
+                contract.get_reactions(accounts.alice),
+                vec![(String::from("🔥"), 2), (String::from("👍"), 1)]
+            );
+            assert_eq!(contract.get_reactions(accounts.bob), Vec::new());
+        }
+
+        #[ink::test]
+        fn distinct_reactions_are_capped_per_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            for i in 0..20 {
+                contract.react(accounts.alice, format!("r{}", i)).unwrap();
+            }
+            assert_eq!(contract.react(accounts.alice, String::from("onemore")), Err(CrudError::InvalidReaction));
+            assert_eq!(contract.react(accounts.alice, String::from("r0")), Ok(()));
+            assert_eq!(contract.get_reactions(accounts.alice).len(), 20);
+        }
+
+        #[ink::test]
+        fn public_read_all_toggle_for_non_creators() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_all_messages(), Err(CrudError::Unauthorized));
+            assert_eq!(contract.set_public_read_all(true), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert!(!contract.is_public_read_all());
+            assert_eq!(contract.set_public_read_all(true), Ok(()));
+            assert!(contract.is_public_read_all());
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_all_messages().unwrap().len(), 1);
+
+            set_caller(accounts.alice);
+            contract.set_public_read_all(false).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.read_all_messages(), Err(CrudError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn public_read_all_hides_private_messages_of_others() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            contract.set_public_read_all(true).unwrap();
+
+            set_caller(accounts.bob);
+            contract.create_private_message(String::from("A secret from Bob")).unwrap();
+            assert_eq!(contract.read_all_messages().unwrap().len(), 2);
+
+            set_caller(accounts.charlie);
+            let senders: Vec<AccountId> = contract.read_all_messages_sorted(true).unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.alice]);
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.read_all_messages().unwrap().len(), 2);
+        }
+
+        #[ink::test]
+        fn purge_sender_removes_every_message_of_sender() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.delete_message().unwrap();
+            contract.create_message(String::from("Second Bob message")).unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!(contract.purge_sender(accounts.bob), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.purge_sender(accounts.bob), Ok(2));
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice, accounts.charlie]);
+            assert_eq!(contract.total_message_count(), 2);
+            assert_eq!(contract.read_message_from(accounts.charlie), Ok(String::from("Hello from Charlie")));
+            assert_eq!(contract.purge_sender(accounts.bob), Ok(0));
+        }
+
+        #[ink::test]
+        fn verify_hash_matches_and_mismatches() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.verify_hash(accounts.bob, [1; 32]), Err(CrudError::AnyMessageFound));
+            contract.create_message_with_hash(String::from("Hello from Bob"), [1; 32]).unwrap();
+            assert_eq!(contract.verify_hash(accounts.bob, [1; 32]), Ok(true));
+            assert_eq!(contract.verify_hash(accounts.bob, [2; 32]), Ok(false));
+
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.verify_hash(accounts.bob, [1; 32]), Ok(false));
+            assert_eq!(contract.verify_hash(accounts.alice, [1; 32]), Ok(false));
+        }
+
+        #[ink::test]
+        fn read_messages_since_is_exclusive_and_ascending() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            for (timestamp, sender) in [(10, accounts.bob), (20, accounts.charlie), (30, accounts.django)] {
+                set_timestamp(timestamp);
+                set_caller(sender);
+                contract.create_message(String::from("Hello from a sender")).unwrap();
+            }
+
+            let senders: Vec<AccountId> = contract.read_messages_since(10).iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.charlie, accounts.django]);
+            assert_eq!(contract.read_messages_since(30), Vec::new());
+        }
+
+        #[ink::test]
+        fn get_all_senders_includes_deleted_only_senders() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+
+            assert_eq!(contract.get_all_senders(false), vec![accounts.alice, accounts.charlie]);
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice, accounts.bob, accounts.charlie]);
+            assert_eq!(contract.get_all_senders(false), contract.get_senders());
+        }
+
+        #[ink::test]
+        fn admin_create_at_preserves_timestamps_and_sorting() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_timestamp(1000);
+            set_caller(accounts.bob);
+            assert_eq!(contract.admin_create_at(accounts.bob, String::from("Imported Bob message"), 5), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.admin_create_at(accounts.bob, String::from("Imported Bob message"), 5), Ok(()));
+            assert_eq!(contract.admin_create_at(accounts.charlie, String::from("Imported Charlie message"), 3), Ok(()));
+            assert_eq!(contract.read_full_message_from(accounts.bob).unwrap().created_at, 5);
+
+            let senders: Vec<AccountId> = contract.read_all_messages_sorted(true).unwrap().iter().map(|m| m.sender).collect();
+            assert_eq!(senders, vec![accounts.alice, accounts.charlie, accounts.bob]);
+        }
+
+        #[ink::test]
+        fn older_import_does_not_replace_the_active_message() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_cooldown(100);
+
+            set_timestamp(1000);
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Live Bob message")).unwrap();
+            set_timestamp(1050);
+
+            set_caller(accounts.alice);
+            contract.admin_create_at(accounts.bob, String::from("Imported Bob message"), 5).unwrap();
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Live Bob message")));
+            assert_eq!(contract.last_activity(), 1000);
+
+            contract.admin_create_at(accounts.bob, String::from("Newer imported Bob message"), 1020).unwrap();
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Newer imported Bob message")));
+            assert_eq!(contract.last_activity(), 1000);
+
+            /* Deleting the newest message falls back to the newest remaining one, not the last stored */
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Live Bob message")));
+        }
+
+        #[ink::test]
+        fn import_does_not_start_the_sender_cooldown() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_cooldown(100);
+
+            set_timestamp(1000);
+            contract.admin_create_at(accounts.bob, String::from("Imported Bob message"), 990).unwrap();
+            contract.admin_create_at(accounts.bob, String::from("Imported Bob message again"), 995).unwrap();
+            assert_eq!(contract.last_activity(), 0);
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            contract.delete_message().unwrap();
+            assert_eq!(contract.create_message(String::from("Live Bob message")), Ok(()));
+        }
+
+        #[ink::test]
+        fn counters_saturate_at_u32_max() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            let mut pinned: Message = contract.messages.get(1).unwrap();
+            pinned.likes = u32::MAX;
+            pinned.edit_count = u32::MAX;
+            contract.messages.insert(1, &pinned);
+            contract.create_counts.insert(accounts.charlie, &u32::MAX);
+
+            contract.update_message(String::from("Hello again from Bob")).unwrap();
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(u32::MAX));
+
+            set_caller(accounts.charlie);
+            contract.like_message(accounts.bob).unwrap();
+            assert_eq!(contract.get_likes(accounts.bob), Ok(u32::MAX));
+            assert_eq!(contract.total_likes(), u32::MAX);
+
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            assert_eq!(contract.create_count(accounts.charlie), u32::MAX);
+
+            contract.reactions.insert((1, String::from("🔥")), &u32::MAX);
+            contract.reaction_keys.insert(1, &vec![String::from("🔥")]);
+            contract.react(accounts.bob, String::from("🔥")).unwrap();
+            assert_eq!(contract.get_reactions(accounts.bob), vec![(String::from("🔥"), u32::MAX)]);
+        }
+
+        #[ink::test]
+        fn exhausted_message_ids_return_overflow() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            contract.next_id = u64::MAX;
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("Hello from Bob")), Err(CrudError::Overflow));
+            assert!(!contract.has_message(accounts.bob));
+        }
+
+        #[ink::test]
+        fn messages_grouped_by_sender_in_multi_mode() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            for (timestamp, sender, text) in [
+                (1, accounts.charlie, "First Charlie message"),
+                (2, accounts.bob, "First Bob message"),
+                (3, accounts.charlie, "Second Charlie message"),
+                (4, accounts.bob, "Second Bob message"),
+            ] {
+                set_timestamp(timestamp);
+                set_caller(sender);
+                contract.create_message(String::from(text)).unwrap();
+            }
+            assert_eq!(contract.messages_grouped(), Err(CrudError::Unauthorized));
+
+            set_caller(accounts.alice);
+            let groups: Vec<(AccountId, Vec<String>)> = contract.messages_grouped()
+                .unwrap()
+                .into_iter()
+                .map(|(sender, messages)| (sender, messages.into_iter().map(|m| m.message).collect()))
+                .collect();
+            assert_eq!(
+                groups,
+                vec![
+                    (accounts.alice, vec![String::from("I created my ULTIMATE CRUD contract for Ronin Club")]),
+                    (accounts.bob, vec![String::from("First Bob message"), String::from("Second Bob message")]),
+                    (accounts.charlie, vec![String::from("First Charlie message"), String::from("Second Charlie message")]),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn forget_me_removes_caller_from_senders() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.forget_me(), Err(CrudError::AnyMessageFound));
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.delete_message().unwrap();
+            contract.create_message(String::from("Second Bob message")).unwrap();
+
+            assert_eq!(contract.forget_me(), Ok(2));
+            assert_eq!(contract.get_all_senders(true), vec![accounts.alice]);
+            assert_eq!(contract.read_latest_from(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.forget_me(), Err(CrudError::AnyMessageFound));
+        }
+
+        #[ink::test]
+        fn forget_me_erases_interactions_but_keeps_comments_of_others() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.like_message(accounts.alice).unwrap();
+            contract.downvote_message(accounts.alice).unwrap();
+            contract.add_comment(accounts.alice, String::from("Comment from Bob")).unwrap();
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.alice, String::from("Comment from Charlie")).unwrap();
+            contract.add_comment(accounts.bob, String::from("Charlie on Bob post")).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.forget_me(), Ok(1));
+
+            assert_eq!(contract.get_likes(accounts.alice), Ok(0));
+            assert_eq!(contract.get_score(accounts.alice), Ok(0));
+            let comments: Vec<Comment> = contract.get_comments(accounts.alice);
+            assert_eq!(comments.len(), 1);
+            assert_eq!(comments[0].0, accounts.charlie);
+            assert_eq!(contract.read_full_message_from(accounts.alice).unwrap().comment_count, 1);
+
+            /* Bob can interact again as a new account */
+            assert_eq!(contract.like_message(accounts.alice), Ok(()));
+            assert_eq!(contract.upvote_message(accounts.alice), Ok(()));
+            assert_eq!(contract.get_score(accounts.alice), Ok(1));
+
+            /* Comments on the removed Bob message do not show up on his next one */
+            contract.create_message(String::from("Bob is back again")).unwrap();
+            assert_eq!(contract.get_comments(accounts.bob), Vec::new());
+        }
+
+        #[ink::test]
+        fn sender_quota_counts_purged_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_per_sender(1);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            contract.forget_me().unwrap();
+            assert_eq!(
+                contract.create_message(String::from("Second Bob message")),
+                Err(CrudError::SenderQuotaExceeded)
+            );
+        }
+
+        #[ink::test]
+        fn storage_cap_is_freed_by_purge() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_messages(3);
+
+            set_caller(accounts.bob);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            contract.delete_message().unwrap();
+            set_caller(accounts.charlie);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+
+            set_caller(accounts.django);
+            assert_eq!(contract.create_message(String::from("Hello from Django")), Err(CrudError::StorageFull));
+            assert_eq!(
+                contract.create_reply(String::from("Django replies to Charlie"), accounts.charlie),
+                Err(CrudError::StorageFull)
+            );
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.purge_deleted(), Ok(1));
+            set_caller(accounts.django);
+            assert_eq!(contract.create_message(String::from("Hello from Django")), Ok(()));
+        }
+
+        #[ink::test]
+        fn zero_storage_cap_is_unlimited() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_max_messages(0);
+
+            for sender in [accounts.bob, accounts.charlie, accounts.django, accounts.eve] {
+                set_caller(sender);
+                assert_eq!(contract.create_message(String::from("Hello from a sender")), Ok(()));
+            }
+        }
+
+        #[ink::test]
+        fn time_bounds_for_zero_one_and_several_messages() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            contract.delete_message().unwrap();
+            assert_eq!(contract.time_bounds(), None);
+
+            set_caller(accounts.bob);
+            set_timestamp(20);
+            contract.create_message(String::from("Hello from Bob")).unwrap();
+            assert_eq!(contract.time_bounds(), Some((20, 20)));
+
+            set_caller(accounts.charlie);
+            set_timestamp(50);
+            contract.create_message(String::from("Hello from Charlie")).unwrap();
+            set_caller(accounts.django);
+            set_timestamp(35);
+            contract.create_message(String::from("Hello from Django")).unwrap();
+            assert_eq!(contract.time_bounds(), Some((20, 50)));
+
+            set_caller(accounts.bob);
+            contract.delete_message().unwrap();
+            assert_eq!(contract.time_bounds(), Some((35, 50)));
+        }
+
+        #[ink::test]
+        fn history_keeps_only_the_last_versions() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(0);
+            contract.create_message(String::from("Bob version number 0")).unwrap();
+            for i in 1..=25u64 {
+                set_timestamp(i);
+                contract.update_message(format!("Bob version number {}", i)).unwrap();
+            }
+
+            let history: Vec<(String, Timestamp)> = contract.get_message_history(accounts.bob).unwrap();
+            assert_eq!(history.len(), MAX_HISTORY);
+            assert_eq!(history[0], (String::from("Bob version number 15"), 15));
+            assert_eq!(history[MAX_HISTORY - 1], (String::from("Bob version number 24"), 24));
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(25));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Bob version number 25")));
+        }
+
+        #[ink::test]
+        fn batch_seed_is_exempt_from_the_create_fee() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_with_fee(100);
+
+            let entries: Vec<(AccountId, String)> = vec![
+                (accounts.bob, String::from("Seeded Bob message")),
+                (accounts.charlie, String::from("Seeded Charlie message")),
+            ];
+            assert_eq!(contract.batch_seed(entries), Ok(2));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Seeded Bob message")));
+            assert_eq!(contract.admin_create_at(accounts.django, String::from("Imported Django message"), 0), Ok(()));
+            assert_eq!(contract.accrued_fees(), 0);
+
+            set_caller(accounts.eve);
+            assert_eq!(contract.create_message(String::from("Unpaid Eve message")), Err(CrudError::InsufficientFee));
+        }
+
+        #[ink::test]
+        fn pinned_private_message_is_hidden_from_others() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.charlie);
+            contract.create_private_message(String::from("Private Charlie message")).unwrap();
+
+            set_caller(accounts.alice);
+            contract.pin_message(accounts.charlie).unwrap();
+            assert!(contract.get_pinned().is_some());
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.get_pinned(), None);
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.get_pinned().map(|m: Message| m.message), Some(String::from("Private Charlie message")));
+        }
+
+        #[ink::test]
+        fn expired_message_cannot_be_updated_and_hides_its_metadata() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(100);
+            contract.create_message_with_ttl(String::from("Short lived Bob message"), 50).unwrap();
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(0));
+            assert_eq!(contract.stats().active, 2);
+
+            set_timestamp(151);
+            assert_eq!(contract.update_message(String::from("Too late Bob update")), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_message_history(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.word_count(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.message_length(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_edit_count(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_likes(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_score(accounts.bob), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.get_message_timestamps(accounts.bob), Err(CrudError::AnyMessageFound));
+
+            let stats: Stats = contract.stats();
+            assert_eq!(stats.total, 2);
+            assert_eq!(stats.active, contract.message_count());
+            assert_eq!(stats.deleted, 0);
+
+            /* Upsert creates a new message in the freed slot */
+            assert_eq!(contract.upsert_message(String::from("Fresh Bob message")), Ok(()));
+            assert_eq!(contract.read_my_message(), Ok(String::from("Fresh Bob message")));
+            assert_eq!(contract.get_edit_count(accounts.bob), Ok(0));
+            assert_eq!(contract.stats().active, 2);
+        }
+
+        #[ink::test]
+        fn dropped_comments_reset_the_comment_count() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new_multi(true);
+
+            set_caller(accounts.bob);
+            set_timestamp(1);
+            contract.create_message(String::from("First Bob message")).unwrap();
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.bob, String::from("Nice message Bob")).unwrap();
+            assert_eq!(contract.read_by_index(1).unwrap().comment_count, 1);
+
+            /* A new message replaces the active one, its comments are dropped */
+            set_caller(accounts.bob);
+            set_timestamp(2);
+            contract.create_message(String::from("Second Bob message")).unwrap();
+            assert_eq!(contract.get_comments(accounts.bob), Vec::new());
+            assert_eq!(contract.read_by_index(1).unwrap().comment_count, 0);
+
+            /* A transferred message loses the comments of its previous owner */
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.bob, String::from("Nice message again")).unwrap();
+            set_caller(accounts.bob);
+            contract.transfer_message(accounts.django).unwrap();
+            assert_eq!(contract.get_comments(accounts.django), Vec::new());
+            assert_eq!(contract.read_full_message_from(accounts.django).unwrap().comment_count, 0);
+        }
+
+        #[ink::test]
+        fn seeding_rejects_the_zero_address() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            let zero: AccountId = AccountId::from([0u8; 32]);
+
+            assert_eq!(contract.admin_create_at(zero, String::from("Imported zero message"), 0), Err(CrudError::ZeroAddress));
+
+            let entries: Vec<(AccountId, String)> = vec![
+                (zero, String::from("Seeded zero message")),
+                (accounts.bob, String::from("Seeded Bob message")),
+            ];
+            assert_eq!(contract.batch_seed(entries), Ok(1));
+            assert_eq!(contract.read_message_from(zero), Err(CrudError::AnyMessageFound));
+            assert_eq!(contract.total_message_count(), 2);
+        }
+
+        #[ink::test]
+        fn hot_messages_check_expiry_at_the_block_timestamp() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.bob);
+            set_timestamp(100);
+            contract.create_message_with_ttl(String::from("Short lived Bob message"), 50).unwrap();
+
+            /* A past now does not bring an expired message back */
+            set_timestamp(200);
+            assert!(contract.hot_messages(120, 10).iter().all(|m| m.sender != accounts.bob));
+
+            /* A future now does not hide a live message */
+            set_timestamp(120);
+            assert!(contract.hot_messages(1_000, 10).iter().any(|m| m.sender == accounts.bob));
+        }
+
+        #[ink::test]
+        fn purge_keeps_the_latest_message_active() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+
+            set_caller(accounts.django);
+            set_timestamp(1);
+            contract.create_message(String::from("Hello from Django")).unwrap();
+            contract.delete_message().unwrap();
+
+            set_caller(accounts.bob);
+            set_timestamp(10);
+            contract.create_message(String::from("Live Bob message")).unwrap();
+            set_caller(accounts.charlie);
+            contract.add_comment(accounts.bob, String::from("Nice message Bob")).unwrap();
+
+            set_caller(accounts.alice);
+            contract.admin_create_at(accounts.bob, String::from("Older imported Bob message"), 5).unwrap();
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Live Bob message")));
+
+            /* Purging shifts indexes, the live message stays active with its comments */
+            assert_eq!(contract.purge_deleted(), Ok(1));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Live Bob message")));
+            assert_eq!(contract.get_comments(accounts.bob).len(), 1);
+            assert_eq!(contract.read_full_message_from(accounts.bob).unwrap().comment_count, 1);
+
+            assert_eq!(contract.purge_deleted(), Ok(0));
+            assert_eq!(contract.read_message_from(accounts.bob), Ok(String::from("Live Bob message")));
+            assert_eq!(contract.get_comments(accounts.bob).len(), 1);
+        }
+
+        #[ink::test]
+        fn exhausted_message_indexes_do_not_overwrite_genesis() {
+            let accounts = accounts();
+            let mut contract = CrudContract::new();
+            contract.messages_len = u32::MAX;
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.create_message(String::from("Hello from Bob")), Err(CrudError::Overflow));
+            assert_eq!(contract.total_message_count(), u32::MAX);
+            assert_eq!(contract.create_count(accounts.bob), 0);
+            assert_eq!(contract.genesis_message().sender, accounts.alice);
+        }
     }
 }